@@ -4,6 +4,25 @@ pub const UNEXPECTED_EOF: u32 = 10002;
 pub const INVALID_ASSIGNMENT_OPERATOR: u32 = 10003;
 pub const INVALID_BINARY_OPERATOR: u32 = 10004;
 pub const INVALID_UNARY_OPERATOR: u32 = 10005;
+pub const INVALID_ESCAPE_SEQUENCE: u32 = 10006;
+pub const INVALID_CODE_POINT: u32 = 10007;
+pub const EMPTY_CHARACTER_LITERAL: u32 = 10008;
+pub const MULTIPLE_CHARACTERS_IN_CHARACTER_LITERAL: u32 = 10009;
+pub const UNTERMINATED_LITERAL: u32 = 10010;
+pub const INVALID_LITERAL_SUFFIX: u32 = 10011;
+pub const INVALID_DIGIT: u32 = 10012;
+pub const LITERAL_OUT_OF_RANGE: u32 = 10013;
+pub const UNTERMINATED_BLOCK_COMMENT: u32 = 10014;
+pub const UNKNOWN_CHARACTER: u32 = 10015;
+pub const INVALID_PLACE_EXPRESSION: u32 = 10016;
+pub const INVALID_RANGE_OPERATOR: u32 = 10017;
+pub const INVALID_POSITIONAL_MEMBER: u32 = 10018;
+pub const VARIADIC_PARAM_NOT_ALLOWED: u32 = 10019;
+pub const DOC_COMMENT_NOT_ATTACHED: u32 = 10020;
+pub const ATTRIBUTE_NOT_ALLOWED: u32 = 10021;
+pub const UNTERMINATED_INTERPOLATION: u32 = 10022;
+pub const ITEM_NOT_ALLOWED: u32 = 10023;
+pub const UNMATCHED_CLOSING_BRACE: u32 = 10024;
 
 pub const _PHASE_RESOLUTION: u32 = 20000;
 pub const UNREACHABLE_MODULE: u32 = 20001;
@@ -16,3 +35,11 @@ pub const MODULE_IS_NOT_VISIBLE: u32 = 20007;
 pub const SYMBOL_IS_NOT_VISIBLE: u32 = 20008;
 pub const GENERIC_ARGUMENT_ON_PATH_PREFIX_NOT_ALLOWED: u32 = 20009;
 pub const PATH_PREFIX_IS_NOT_RESOLVED: u32 = 20010;
+pub const DUPLICATED_BINDING: u32 = 20011;
+pub const REFUTABLE_PATTERN: u32 = 20012;
+pub const UNKNOWN_ABI: u32 = 20013;
+pub const INVALID_LINK_NAME: u32 = 20014;
+pub const ARGUMENT_COUNT_MISMATCH: u32 = 20015;
+pub const BREAK_OUTSIDE_OF_LOOP: u32 = 20016;
+pub const UNDECLARED_LABEL: u32 = 20017;
+pub const INVALID_VISIBILITY_PATH: u32 = 20018;
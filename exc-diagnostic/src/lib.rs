@@ -20,6 +20,11 @@ impl DiagnosticsSender {
         &self.file
     }
 
+    /// Sends an already built diagnostics as is, e.g. to forward it from another sender.
+    pub fn send(&self, diagnostics: Diagnostics) {
+        self.sender.send(diagnostics).unwrap();
+    }
+
     pub fn hint(&self, span: Span, message: String) {
         self.sender
             .send(Diagnostics {
@@ -1 +1,7 @@
-pub const CODE: u32 = 0001;
+pub const _PHASE_PARSE: u32 = 10000;
+pub const TEXT_DIRECTION_CODEPOINT: u32 = 10001;
+
+pub const _PHASE_RESOLUTION: u32 = 20000;
+pub const CONFUSABLE_IDENTIFIERS: u32 = 20001;
+pub const UNKNOWN_ATTRIBUTE: u32 = 20002;
+pub const MISPLACED_ATTRIBUTE: u32 = 20003;
@@ -1,10 +1,12 @@
 mod node_id;
 mod node_id_allocator;
 mod punctuated;
+mod shift_span;
 
 pub use node_id::*;
 pub use node_id_allocator::*;
 pub use punctuated::*;
+pub use shift_span::*;
 
 use crate::{Token, TokenKind, TokenLiteral};
 use exc_diagnostic::DiagnosticsSender;
@@ -25,10 +27,55 @@ pub struct ASTModule {
     pub items: Vec<ASTModuleItem>,
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct ASTDocComment {
+    pub id: NodeId,
+    pub span: Span,
+    pub lines: Vec<Token>, // ## ...
+}
+
+impl ASTDocComment {
+    /// Returns the text of the doc comment, with `##` and a single leading space stripped from each line.
+    pub fn content(&self) -> String {
+        self.lines
+            .iter()
+            .filter_map(|line| match line.kind {
+                TokenKind::DocComment { symbol } => Some(symbol.to_str()),
+                _ => None,
+            })
+            .map(|line| {
+                let line = line.trim_end_matches(['\n', '\r']);
+                line.strip_prefix(' ').unwrap_or(line)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTAttribute {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_at: Token,                // @
+    pub identifier: Id,                 // identifier
+    pub args: Option<ASTAttributeArgs>, // ( ... )
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTAttributeArgs {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token, // (
+    pub args: Punctuated<ASTExpr, { PUNCUATION_KIND_COMMA }>,
+    pub token_paren_close: Token, // )
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTModuleItem {
     pub id: NodeId,
     pub span: Span,
+    pub doc_comment: Option<ASTDocComment>, // ## ...
+    pub attributes: Vec<ASTAttribute>,      // @identifier(...)
     pub kind: ASTModuleItemKind,
 }
 
@@ -36,23 +83,58 @@ pub struct ASTModuleItem {
 pub enum ASTModuleItemKind {
     Use(Arc<ASTUse>),
     AliasDef(Arc<ASTAliasDef>),
+    ConstDef(Arc<ASTConstDef>),
+    StaticDef(Arc<ASTStaticDef>),
     ModuleDecl(Arc<ASTModuleDecl>),
     ModuleDef(Arc<ASTModuleDef>),
     ExternBlock(ASTExternBlock),
     FnDef(Arc<ASTFnDef>),
     StructDef(Arc<ASTStructDef>),
+    EnumDef(Arc<ASTEnumDef>),
     InterfaceDef(Arc<ASTInterfaceDef>),
     ImplBlock(ASTImplBlock),
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct ASTVisibility {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_pub: Id,                   // pub
+    pub scope: Option<ASTVisibilityScope>, // (super) | (module) | (in path)
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTVisibilityScope {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token, // (
+    pub kind: ASTVisibilityScopeKind,
+    pub token_paren_close: Token, // )
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTVisibilityScopeKind {
+    Super_(Id),               // super
+    Module(Id),               // module
+    In(ASTVisibilityScopeIn), // in path
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTVisibilityScopeIn {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_in: Id, // in
+    pub path: Punctuated<ASTUsePathPrefixSegmentKind, { PUNCUATION_KIND_PATH_SEP }>, // super::identifier
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTUse {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>, // pub
-    pub keyword_use: Id,         // use
-    pub path: ASTUsePath,        // self::super::identifier::*
-    pub token_semicolon: Token,  // ;
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_use: Id,                   // use
+    pub path: ASTUsePath,                  // self::super::identifier::*
+    pub token_semicolon: Token,            // ;
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -139,32 +221,60 @@ pub struct ASTUsePathItemGroup {
 pub struct ASTAliasDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>, // pub
-    pub keyword_alias: Id,       // alias
-    pub identifier: Id,          // identifier
-    pub token_assign: Token,     // =
-    pub ty: ASTTy,               // ty
-    pub token_semicolon: Token,  // ;
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_alias: Id,                 // alias
+    pub identifier: Id,                    // identifier
+    pub token_assign: Token,               // =
+    pub ty: ASTTy,                         // ty
+    pub token_semicolon: Token,            // ;
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTConstDef {
+    pub id: NodeId,
+    pub span: Span,
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_const: Id,                 // const
+    pub identifier: Id,                    // identifier
+    pub token_colon: Token,                // :
+    pub ty: ASTTy,                         // ty
+    pub token_assign: Token,               // =
+    pub expr: ASTExpr,                     // expr
+    pub token_semicolon: Token,            // ;
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTStaticDef {
+    pub id: NodeId,
+    pub span: Span,
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_static: Id,                // static
+    pub identifier: Id,                    // identifier
+    pub token_colon: Token,                // :
+    pub ty: ASTTy,                         // ty
+    pub token_assign: Token,               // =
+    pub expr: ASTExpr,                     // expr
+    pub token_semicolon: Token,            // ;
 }
 
 #[derive(Debug, Clone, Hash)]
 pub struct ASTModuleDecl {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>, // pub
-    pub keyword_module: Id,      // module
-    pub identifier: Id,          // identifier
-    pub token_semicolon: Token,  // ;
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_module: Id,                // module
+    pub identifier: Id,                    // identifier
+    pub token_semicolon: Token,            // ;
 }
 
 #[derive(Debug, Clone, Hash)]
 pub struct ASTModuleDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>, // pub
-    pub keyword_module: Id,      // module
-    pub identifier: Id,          // identifier
-    pub token_brace_open: Token, // {
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_module: Id,                // module
+    pub identifier: Id,                    // identifier
+    pub token_brace_open: Token,           // {
     pub items: Vec<ASTModuleItem>,
     pub token_brace_close: Token, // }
 }
@@ -173,16 +283,26 @@ pub struct ASTModuleDef {
 pub struct ASTExternBlock {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_extern: Id,      // extern
-    pub token_brace_open: Token, // {
+    pub keyword_extern: Id,             // extern
+    pub abi: Option<ASTExternBlockAbi>, // "C"
+    pub token_brace_open: Token,        // {
     pub items: Vec<ASTExternBlockItem>,
     pub token_brace_close: Token, // }
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExternBlockAbi {
+    pub id: NodeId,
+    pub span: Span,
+    pub literal: TokenLiteral,
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTExternBlockItem {
     pub id: NodeId,
     pub span: Span,
+    pub doc_comment: Option<ASTDocComment>, // ## ...
+    pub attributes: Vec<ASTAttribute>,      // @identifier(...)
     pub kind: ASTExternBlockItemKind,
 }
 
@@ -198,21 +318,28 @@ pub enum ASTExternBlockItemKind {
 pub struct ASTPrototypeDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>,                                   // pub
-    pub keyword_prototype: Id,                                     // prototype
-    pub identifier: Id,                                            // identifier
-    pub token_paren_open: Token,                                   // (
+    pub visibility: Option<ASTVisibility>, // pub
+    pub keyword_prototype: Id,             // prototype
+    pub identifier: Id,                    // identifier
+    pub token_paren_open: Token,           // (
     pub params: Punctuated<ASTFnParam, { PUNCUATION_KIND_COMMA }>, // ...
-    pub token_paren_close: Token,                                  // )
-    pub result: Option<ASTFnResult>,                               // -> ty
-    pub token_semicolon: Token,                                    // ;
+    pub token_ellipsis: Option<Token>,     // ..., after the fixed params
+    pub token_paren_close: Token,          // )
+    pub result: Option<ASTFnResult>,       // -> ty
+    pub token_semicolon: Token,            // ;
+}
+
+impl ASTPrototypeDef {
+    pub fn is_variadic(&self) -> bool {
+        self.token_ellipsis.is_some()
+    }
 }
 
 #[derive(Debug, Clone, Hash)]
 pub struct ASTFnDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>,                // pub
+    pub visibility: Option<ASTVisibility>,      // pub
     pub keyword_fn: Id,                         // fn
     pub identifier: Id,                         // identifier
     pub generic_param: Option<ASTGenericParam>, // <...>
@@ -228,9 +355,9 @@ pub struct ASTFnDef {
 pub struct ASTFnParam {
     pub id: NodeId,
     pub span: Span,
-    pub identifier: Id,     // identifier
-    pub token_colon: Token, // :
-    pub ty: ASTTy,          // ty
+    pub pattern: ASTPattern, // pattern
+    pub token_colon: Token,  // :
+    pub ty: ASTTy,           // ty
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -245,7 +372,7 @@ pub struct ASTFnResult {
 pub struct ASTStructDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>,                // pub
+    pub visibility: Option<ASTVisibility>,      // pub
     pub keyword_struct: Id,                     // struct
     pub identifier: Id,                         // identifier
     pub generic_param: Option<ASTGenericParam>, // <...>
@@ -259,16 +386,66 @@ pub struct ASTStructDef {
 pub struct ASTStructDefField {
     pub id: NodeId,
     pub span: Span,
-    pub identifier: Id,     // identifier
-    pub token_colon: Token, // :
-    pub ty: ASTTy,          // ty
+    pub doc_comment: Option<ASTDocComment>, // ## ...
+    pub attributes: Vec<ASTAttribute>,      // @identifier(...)
+    pub identifier: Id,                     // identifier
+    pub token_colon: Token,                 // :
+    pub ty: ASTTy,                          // ty
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTEnumDef {
+    pub id: NodeId,
+    pub span: Span,
+    pub visibility: Option<ASTVisibility>,      // pub
+    pub keyword_enum: Id,                       // enum
+    pub identifier: Id,                         // identifier
+    pub generic_param: Option<ASTGenericParam>, // <...>
+    pub generic_where: Option<ASTGenericWhere>, // where ...
+    pub token_brace_open: Token,                // {
+    pub variants: Punctuated<ASTEnumDefVariant, { PUNCUATION_KIND_COMMA }>,
+    pub token_brace_close: Token, // }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTEnumDefVariant {
+    pub id: NodeId,
+    pub span: Span,
+    pub doc_comment: Option<ASTDocComment>, // ## ...
+    pub identifier: Id,                     // identifier
+    pub kind: ASTEnumDefVariantKind,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTEnumDefVariantKind {
+    Unit,                            // identifier
+    Tuple(ASTEnumDefVariantTuple),   // identifier(ty, ...)
+    Struct(ASTEnumDefVariantStruct), // identifier { identifier: ty, ... }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTEnumDefVariantTuple {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token,                             // (
+    pub items: Punctuated<ASTTy, { PUNCUATION_KIND_COMMA }>, // ty, ...
+    pub token_paren_close: Token,                            // )
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTEnumDefVariantStruct {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_brace_open: Token, // {
+    pub fields: Punctuated<ASTStructDefField, { PUNCUATION_KIND_COMMA }>,
+    pub token_brace_close: Token, // }
 }
 
 #[derive(Debug, Clone, Hash)]
 pub struct ASTInterfaceDef {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_pub: Option<Id>,                // pub
+    pub visibility: Option<ASTVisibility>,      // pub
     pub keyword_interface: Id,                  // interface
     pub identifier: Id,                         // identifier
     pub generic_param: Option<ASTGenericParam>, // <...>
@@ -282,6 +459,7 @@ pub struct ASTInterfaceDef {
 pub struct ASTInterfaceDefItem {
     pub id: NodeId,
     pub span: Span,
+    pub doc_comment: Option<ASTDocComment>, // ## ...
     pub kind: ASTInterfaceDefItemKind,
 }
 
@@ -331,6 +509,8 @@ pub struct ASTImplBlockInterface {
 pub struct ASTImplBlockItem {
     pub id: NodeId,
     pub span: Span,
+    pub doc_comment: Option<ASTDocComment>, // ## ...
+    pub attributes: Vec<ASTAttribute>,      // @identifier(...)
     pub kind: ASTImplBlockItemKind,
 }
 
@@ -406,12 +586,22 @@ pub struct ASTStmtBlock {
     pub token_brace_close: Token, // }
 }
 
+impl ASTStmtBlock {
+    /// Returns the trailing expression without a semicolon, which is the value of the block.
+    pub fn tail_expr(&self) -> Option<&ASTExpr> {
+        match self.stmts.last().map(|stmt| &stmt.kind) {
+            Some(ASTStmtKind::Expr(ast)) if ast.token_semicolon.is_none() => Some(&ast.expr),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTStmtLet {
     pub id: NodeId,
     pub span: Span,
     pub keyword_let: Id,              // let
-    pub identifier: Id,               // identifier
+    pub pattern: ASTPattern,          // pattern
     pub ty: Option<ASTStmtLetTy>,     // : ty
     pub expr: Option<ASTStmtLetExpr>, // = expression
     pub token_semicolon: Token,       // ;
@@ -434,49 +624,33 @@ pub struct ASTStmtLetExpr {
 }
 
 #[derive(Debug, Clone, Hash)]
-pub struct ASTStmtIf {
+pub struct ASTLoopLabel {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_if: Id,           // if
-    pub expr: ASTExpr,            // expression
-    pub stmt_block: ASTStmtBlock, // { ... }
-    pub else_ifs: Vec<ASTStmtIfElseIf>,
-    pub else_: Option<ASTStmtIfElse>,
-}
-
-#[derive(Debug, Clone, Hash)]
-pub struct ASTStmtIfElseIf {
-    pub id: NodeId,
-    pub span: Span,
-    pub keyword_else: Id,         // else
-    pub keyword_if: Id,           // if
-    pub expr: ASTExpr,            // expression
-    pub stmt_block: ASTStmtBlock, // { ... }
-}
-
-#[derive(Debug, Clone, Hash)]
-pub struct ASTStmtIfElse {
-    pub id: NodeId,
-    pub span: Span,
-    pub keyword_else: Id,         // else
-    pub stmt_block: ASTStmtBlock, // { ... }
+    pub label: Id,          // 'label
+    pub token_colon: Token, // :
 }
 
 #[derive(Debug, Clone, Hash)]
-pub struct ASTStmtLoop {
+pub struct ASTStmtWhile {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_loop: Id,         // loop
-    pub stmt_block: ASTStmtBlock, // { ... }
+    pub label: Option<ASTLoopLabel>, // 'label:
+    pub keyword_while: Id,           // while
+    pub expr: ASTExpr,               // expression
+    pub stmt_block: ASTStmtBlock,    // { ... }
 }
 
 #[derive(Debug, Clone, Hash)]
-pub struct ASTStmtWhile {
+pub struct ASTStmtFor {
     pub id: NodeId,
     pub span: Span,
-    pub keyword_while: Id,        // while
-    pub expr: ASTExpr,            // expression
-    pub stmt_block: ASTStmtBlock, // { ... }
+    pub label: Option<ASTLoopLabel>, // 'label:
+    pub keyword_for: Id,             // for
+    pub identifier: Id,              // identifier
+    pub keyword_in: Id,              // in
+    pub expr: ASTExpr,               // expression
+    pub stmt_block: ASTStmtBlock,    // { ... }
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -484,6 +658,8 @@ pub struct ASTStmtBreak {
     pub id: NodeId,
     pub span: Span,
     pub keyword_break: Id,      // break
+    pub label: Option<Id>,      // 'label
+    pub expr: Option<ASTExpr>,  // value of the loop
     pub token_semicolon: Token, // ;
 }
 
@@ -492,6 +668,7 @@ pub struct ASTStmtContinue {
     pub id: NodeId,
     pub span: Span,
     pub keyword_continue: Id,   // continue
+    pub label: Option<Id>,      // 'label
     pub token_semicolon: Token, // ;
 }
 
@@ -572,8 +749,8 @@ impl ASTStmtAssignmentOperatorKind {
 pub struct ASTStmtExpr {
     pub id: NodeId,
     pub span: Span,
-    pub expr: ASTExpr,          // expression
-    pub token_semicolon: Token, // ;
+    pub expr: ASTExpr,                  // expression
+    pub token_semicolon: Option<Token>, // ; (optional after block-like expressions and at the end of a block)
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -585,11 +762,9 @@ pub struct ASTStmt {
 
 #[derive(Debug, Clone, Hash)]
 pub enum ASTStmtKind {
-    Block(ASTStmtBlock),
     Let(ASTStmtLet),
-    If(ASTStmtIf),
-    Loop(ASTStmtLoop),
     While(ASTStmtWhile),
+    For(ASTStmtFor),
     Break(ASTStmtBreak),
     Continue(ASTStmtContinue),
     Return(ASTStmtReturn),
@@ -606,15 +781,81 @@ pub struct ASTExpr {
 
 #[derive(Debug, Clone, Hash)]
 pub enum ASTExprKind {
+    Range(ASTExprRange),                 // Precedence 6 : infix, prefix or postfix
     Binary(ASTExprBinary),               // Precedence 5 : postfix
     As(ASTExprAs),                       // Precedence 4 : postfix
     Unary(ASTExprUnary),                 // Precedence 3 : prefix
     Call(ASTExprCall),                   // Precedence 2 : postfix
     Member(ASTExprMember),               // Precedence 2 : postfix
+    Index(ASTExprIndex),                 // Precedence 2 : postfix
     Paren(ASTExprParen),                 // Precedence 1 : prefix
+    Tuple(ASTExprTuple),                 // Precedence 1 : prefix
     Path(ASTExprPath),                   // Precedence 1 : single item
     Literal(ASTExprLiteral),             // Precedence 1 : single item
     StructLiteral(ASTExprStructLiteral), // Precedence 1 : single item
+    ArrayLiteral(ASTExprArrayLiteral),   // Precedence 1 : single item
+    ArrayRepeat(ASTExprArrayRepeat),     // Precedence 1 : single item
+    Block(ASTStmtBlock),                 // Precedence 1 : single item
+    If(ASTExprIf),                       // Precedence 1 : single item
+    Loop(ASTExprLoop),                   // Precedence 1 : single item
+    Match(ASTExprMatch),                 // Precedence 1 : single item
+    Closure(ASTExprClosure),             // Precedence 1 : single item
+    InterpolatedString(ASTExprInterpolatedString), // Precedence 1 : single item
+}
+
+impl ASTExpr {
+    /// Returns `true` if the expression denotes a memory location, so that it can be assigned to.
+    pub fn is_place(&self) -> bool {
+        match &self.kind {
+            ASTExprKind::Path(_) | ASTExprKind::Member(_) | ASTExprKind::Index(_) => true,
+            ASTExprKind::Unary(ast) => match ast.operator.kind {
+                ASTExprUnaryOperatorKind::Dereference => true,
+                _ => false,
+            },
+            ASTExprKind::Paren(ast) => ast.expr.is_place(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprRange {
+    pub id: NodeId,
+    pub span: Span,
+    pub start: Option<Box<ASTExpr>>,
+    pub operator: ASTExprRangeOperator,
+    pub end: Option<Box<ASTExpr>>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprRangeOperator {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_operator: Token,
+    pub kind: ASTExprRangeOperatorKind,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTExprRangeOperatorKind {
+    Exclusive, // ..
+    Inclusive, // ..=
+}
+
+impl ASTExprRangeOperatorKind {
+    pub fn from_token(token: &Token, diagnostics: &DiagnosticsSender) -> Result<Self, ()> {
+        match token.kind {
+            TokenKind::Rng => Ok(Self::Exclusive),
+            TokenKind::RngInclusive => Ok(Self::Inclusive),
+            _ => {
+                diagnostics.error(
+                    exc_diagnostic::error_codes::INVALID_RANGE_OPERATOR,
+                    token.span,
+                    format!("{} is not a valid range operator", token.kind.into_symbol()),
+                );
+                Err(())
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -772,8 +1013,31 @@ pub struct ASTExprMember {
     pub id: NodeId,
     pub span: Span,
     pub expr: Box<ASTExpr>,
-    pub token_dot: Token, // .
-    pub member: Id,       // identifier
+    pub token_dot: Token,          // .
+    pub member: ASTExprMemberKind, // identifier or index
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTExprMemberKind {
+    Named(Id),                           // identifier
+    Positional(ASTExprMemberPositional), // index, e.g. `0` in `t.0`
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprMemberPositional {
+    pub id: NodeId,
+    pub span: Span,
+    pub index: usize,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprIndex {
+    pub id: NodeId,
+    pub span: Span,
+    pub expr: Box<ASTExpr>,
+    pub token_bracket_open: Token,  // [
+    pub index: Box<ASTExpr>,        // expression
+    pub token_bracket_close: Token, // ]
 }
 
 #[derive(Debug, Clone, Hash)]
@@ -785,6 +1049,17 @@ pub struct ASTExprParen {
     pub token_paren_close: Token, // )
 }
 
+/// A tuple expression, e.g. `()`, `(a,)` or `(a, b)`.
+/// A single element without a trailing comma is a parenthesized expression instead.
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprTuple {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token, // (
+    pub elements: Punctuated<ASTExpr, { PUNCUATION_KIND_COMMA }>,
+    pub token_paren_close: Token, // )
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTExprPath {
     pub id: NodeId,
@@ -799,6 +1074,22 @@ pub struct ASTExprLiteral {
     pub literal: TokenLiteral,
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprInterpolatedString {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_head: Token, // f"...{ or f"..."
+    pub interpolations: Vec<ASTExprInterpolatedStringInterpolation>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprInterpolatedStringInterpolation {
+    pub id: NodeId,
+    pub span: Span,
+    pub expr: ASTExpr,     // expression
+    pub token_tail: Token, // }...{ or }..."
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTExprStructLiteral {
     pub id: NodeId,
@@ -818,6 +1109,214 @@ pub struct ASTExprStructLiteralField {
     pub expr: ASTExpr,      // expression
 }
 
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprArrayLiteral {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_bracket_open: Token, // [
+    pub elements: Punctuated<ASTExpr, { PUNCUATION_KIND_COMMA }>,
+    pub token_bracket_close: Token, // ]
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprArrayRepeat {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_bracket_open: Token,  // [
+    pub expr: Box<ASTExpr>,         // expression
+    pub token_semicolon: Token,     // ;
+    pub count: Box<ASTExpr>,        // expression
+    pub token_bracket_close: Token, // ]
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprIf {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_if: Id,           // if
+    pub expr: Box<ASTExpr>,       // expression
+    pub stmt_block: ASTStmtBlock, // { ... }
+    pub else_ifs: Vec<ASTExprIfElseIf>,
+    pub else_: Option<ASTExprIfElse>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprIfElseIf {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_else: Id,         // else
+    pub keyword_if: Id,           // if
+    pub expr: ASTExpr,            // expression
+    pub stmt_block: ASTStmtBlock, // { ... }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprIfElse {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_else: Id,         // else
+    pub stmt_block: ASTStmtBlock, // { ... }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprLoop {
+    pub id: NodeId,
+    pub span: Span,
+    pub label: Option<ASTLoopLabel>, // 'label:
+    pub keyword_loop: Id,            // loop
+    pub stmt_block: ASTStmtBlock,    // { ... }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprMatch {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_match: Id,       // match
+    pub expr: Box<ASTExpr>,      // expression
+    pub token_brace_open: Token, // {
+    pub arms: Punctuated<ASTExprMatchArm, { PUNCUATION_KIND_COMMA }>,
+    pub token_brace_close: Token, // }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprClosure {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_fn: Id,          // fn
+    pub token_paren_open: Token, // (
+    pub params: Punctuated<ASTFnParam, { PUNCUATION_KIND_COMMA }>,
+    pub token_paren_close: Token,    // )
+    pub result: Option<ASTFnResult>, // -> ty
+    pub stmt_block: ASTStmtBlock,    // { ... }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTExprMatchArm {
+    pub id: NodeId,
+    pub span: Span,
+    pub pattern: ASTPattern,
+    pub token_fat_arrow: Token, // =>
+    pub body: ASTExprMatchArmBody,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTExprMatchArmBody {
+    Block(ASTStmtBlock), // the comma after it can be omitted
+    Expr(Box<ASTExpr>),
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPattern {
+    pub id: NodeId,
+    pub span: Span,
+    pub kind: ASTPatternKind,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub enum ASTPatternKind {
+    Wildcard(ASTPatternWildcard),       // _
+    Binding(ASTPatternBinding),         // identifier
+    Literal(ASTPatternLiteral),         // literal
+    Range(ASTPatternRange),             // literal..literal
+    Path(ASTPatternPath),               // path
+    Struct(ASTPatternStruct),           // path { field: pattern, ... }
+    TupleStruct(ASTPatternTupleStruct), // path(pattern, ...)
+    Tuple(ASTPatternTuple),             // (pattern, ...)
+    Array(ASTPatternArray),             // [pattern, ...]
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternWildcard {
+    pub id: NodeId,
+    pub span: Span,
+    pub keyword_underscore: Id, // _
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternBinding {
+    pub id: NodeId,
+    pub span: Span,
+    pub identifier: Id, // identifier
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternLiteral {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_minus: Option<Token>, // -
+    pub literal: TokenLiteral,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternRange {
+    pub id: NodeId,
+    pub span: Span,
+    pub start: Option<ASTPatternLiteral>,
+    pub operator: ASTExprRangeOperator,
+    pub end: Option<ASTPatternLiteral>,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternPath {
+    pub id: NodeId,
+    pub span: Span,
+    pub path: ASTPath,
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternStruct {
+    pub id: NodeId,
+    pub span: Span,
+    pub path: ASTPath,
+    pub token_brace_open: Token, // {
+    pub fields: Punctuated<ASTPatternStructField, { PUNCUATION_KIND_COMMA }>,
+    pub token_brace_close: Token, // }
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternStructField {
+    pub id: NodeId,
+    pub span: Span,
+    pub identifier: Id,                                // identifier
+    pub pattern: Option<ASTPatternStructFieldPattern>, // : pattern (binds the identifier if omitted)
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternStructFieldPattern {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_colon: Token,  // :
+    pub pattern: ASTPattern, // pattern
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternTupleStruct {
+    pub id: NodeId,
+    pub span: Span,
+    pub path: ASTPath,
+    pub token_paren_open: Token, // (
+    pub fields: Punctuated<ASTPattern, { PUNCUATION_KIND_COMMA }>,
+    pub token_paren_close: Token, // )
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternTuple {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token, // (
+    pub items: Punctuated<ASTPattern, { PUNCUATION_KIND_COMMA }>,
+    pub token_paren_close: Token, // )
+}
+
+#[derive(Debug, Clone, Hash)]
+pub struct ASTPatternArray {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_bracket_open: Token, // [
+    pub items: Punctuated<ASTPattern, { PUNCUATION_KIND_COMMA }>,
+    pub token_bracket_close: Token, // ]
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTPath {
     pub id: NodeId,
@@ -843,6 +1342,7 @@ pub struct ASTTy {
 #[derive(Debug, Clone, Hash)]
 pub enum ASTTyKind {
     Paren(ASTTyParen),
+    Tuple(ASTTyTuple),
     Span(ASTTySpan),
     Array(ASTTyArray),
     FnPointer(ASTTyFnPointer),
@@ -858,6 +1358,17 @@ pub struct ASTTyParen {
     pub token_paren_close: Token, // )
 }
 
+/// A tuple type, e.g. `()`, `(T,)` or `(T, U)`.
+/// A single type without a trailing comma is a parenthesized type instead.
+#[derive(Debug, Clone, Hash)]
+pub struct ASTTyTuple {
+    pub id: NodeId,
+    pub span: Span,
+    pub token_paren_open: Token,                             // (
+    pub items: Punctuated<ASTTy, { PUNCUATION_KIND_COMMA }>, // ty, ty, ..
+    pub token_paren_close: Token,                            // )
+}
+
 #[derive(Debug, Clone, Hash)]
 pub struct ASTTySpan {
     pub id: NodeId,
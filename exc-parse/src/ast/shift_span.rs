@@ -0,0 +1,254 @@
+use super::*;
+use exc_span::Pos;
+
+/// Moves every span of a node with the given position mapping.
+/// Used to carry nodes over into another source file without reparsing them, e.g. after an edit.
+pub trait ShiftSpan {
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos);
+}
+
+impl ShiftSpan for Span {
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        self.low = shift(self.low);
+        self.high = shift(self.high);
+    }
+}
+
+impl ShiftSpan for Token {
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        self.span.shift_span(shift);
+    }
+}
+
+impl ShiftSpan for Id {
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        self.span.shift_span(shift);
+    }
+}
+
+impl<T> ShiftSpan for Option<T>
+where
+    T: ShiftSpan,
+{
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        if let Some(item) = self {
+            item.shift_span(shift);
+        }
+    }
+}
+
+impl<T> ShiftSpan for Vec<T>
+where
+    T: ShiftSpan,
+{
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        for item in self {
+            item.shift_span(shift);
+        }
+    }
+}
+
+impl<T> ShiftSpan for Box<T>
+where
+    T: ShiftSpan,
+{
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        self.as_mut().shift_span(shift);
+    }
+}
+
+impl<T> ShiftSpan for Arc<T>
+where
+    T: ShiftSpan + Clone,
+{
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        Arc::make_mut(self).shift_span(shift);
+    }
+}
+
+impl<T, const KIND: u8> ShiftSpan for Punctuated<T, KIND>
+where
+    T: ShiftSpan + std::fmt::Debug + Clone + std::hash::Hash,
+{
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        for item in &mut self.items {
+            match item {
+                PunctuatedItem::Punctuated { item, punctuation } => {
+                    item.shift_span(shift);
+                    punctuation.shift_span(shift);
+                }
+                PunctuatedItem::NotPunctuated { item } => {
+                    item.shift_span(shift);
+                }
+            }
+        }
+    }
+}
+
+impl ShiftSpan for ASTEnumDefVariantKind {
+    fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+        match self {
+            Self::Unit => {}
+            Self::Tuple(item) => item.shift_span(shift),
+            Self::Struct(item) => item.shift_span(shift),
+        }
+    }
+}
+
+/// Implements `ShiftSpan` for a node by shifting the listed fields.
+macro_rules! impl_shift_span_struct {
+    ($($ty:ident { $($field:ident),* $(,)? })*) => {
+        $(
+            impl ShiftSpan for $ty {
+                fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+                    $(self.$field.shift_span(shift);)*
+                }
+            }
+        )*
+    };
+}
+
+/// Implements `ShiftSpan` for an enum whose variants are all single-field tuple variants.
+macro_rules! impl_shift_span_enum {
+    ($($ty:ident { $($variant:ident),* $(,)? })*) => {
+        $(
+            impl ShiftSpan for $ty {
+                fn shift_span(&mut self, shift: &dyn Fn(Pos) -> Pos) {
+                    match self {
+                        $(Self::$variant(item) => item.shift_span(shift),)*
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_shift_span_struct! {
+    ASTModule { span, items }
+    ASTDocComment { span, lines }
+    ASTAttribute { span, token_at, identifier, args }
+    ASTAttributeArgs { span, token_paren_open, args, token_paren_close }
+    ASTModuleItem { span, doc_comment, attributes, kind }
+    ASTVisibility { span, keyword_pub, scope }
+    ASTVisibilityScope { span, token_paren_open, kind, token_paren_close }
+    ASTVisibilityScopeIn { span, keyword_in, path }
+    ASTUse { span, visibility, keyword_use, path, token_semicolon }
+    ASTUsePath { span, prefix, item }
+    ASTUsePathPrefix { span, segments }
+    ASTUsePathPrefixSegment { span, kind, token_path_sep }
+    ASTUsePathItem { span, kind }
+    ASTUsePathItemSingle { span, identifier, alias }
+    ASTUsePathItemSingleAlias { span, keyword_as, identifier }
+    ASTUsePathItemGroup { span, token_brace_open, items, token_brace_close }
+    ASTAliasDef { span, visibility, keyword_alias, identifier, token_assign, ty, token_semicolon }
+    ASTConstDef { span, visibility, keyword_const, identifier, token_colon, ty, token_assign, expr, token_semicolon }
+    ASTStaticDef { span, visibility, keyword_static, identifier, token_colon, ty, token_assign, expr, token_semicolon }
+    ASTModuleDecl { span, visibility, keyword_module, identifier, token_semicolon }
+    ASTModuleDef { span, visibility, keyword_module, identifier, token_brace_open, items, token_brace_close }
+    ASTExternBlock { span, keyword_extern, abi, token_brace_open, items, token_brace_close }
+    ASTExternBlockAbi { span }
+    ASTExternBlockItem { span, doc_comment, attributes, kind }
+    ASTPrototypeDef { span, visibility, keyword_prototype, identifier, token_paren_open, params, token_ellipsis, token_paren_close, result, token_semicolon }
+    ASTFnDef { span, visibility, keyword_fn, identifier, generic_param, token_paren_open, params, token_paren_close, result, generic_where, stmt_block }
+    ASTFnParam { span, pattern, token_colon, ty }
+    ASTFnResult { span, token_arrow, ty }
+    ASTStructDef { span, visibility, keyword_struct, identifier, generic_param, generic_where, token_brace_open, fields, token_brace_close }
+    ASTStructDefField { span, doc_comment, attributes, identifier, token_colon, ty }
+    ASTEnumDef { span, visibility, keyword_enum, identifier, generic_param, generic_where, token_brace_open, variants, token_brace_close }
+    ASTEnumDefVariant { span, doc_comment, identifier, kind }
+    ASTEnumDefVariantTuple { span, token_paren_open, items, token_paren_close }
+    ASTEnumDefVariantStruct { span, token_brace_open, fields, token_brace_close }
+    ASTInterfaceDef { span, visibility, keyword_interface, identifier, generic_param, generic_where, token_brace_open, items, token_brace_close }
+    ASTInterfaceDefItem { span, doc_comment, kind }
+    ASTInterfaceDefItemFnDecl { span, keyword_fn, identifier, generic_param, token_paren_open, params, token_paren_close, result, generic_where, token_semicolon }
+    ASTImplBlock { span, keyword_impl, generic_param, ty, interface, generic_where, token_brace_open, items, token_brace_close }
+    ASTImplBlockInterface { span, keyword_interface, path }
+    ASTImplBlockItem { span, doc_comment, attributes, kind }
+    ASTGenericParam { span, token_angle_open, items, token_angle_close }
+    ASTGenericParamItem { span, identifier }
+    ASTGenericWhere { span, keyword_where, items }
+    ASTGenericWhereItem { span, identifier, token_colon, condition }
+    ASTGenericWhereItemCondition { span, path, extra_items }
+    ASTGenericWhereItemConditionItem { span, token_plus, path }
+    ASTGenericArg { span, token_angle_open, args, token_angle_close }
+    ASTStmtBlock { span, token_brace_open, stmts, token_brace_close }
+    ASTStmtLet { span, keyword_let, pattern, ty, expr, token_semicolon }
+    ASTStmtLetTy { span, token_colon, ty }
+    ASTStmtLetExpr { span, token_assign, expr }
+    ASTLoopLabel { span, label, token_colon }
+    ASTStmtWhile { span, label, keyword_while, expr, stmt_block }
+    ASTStmtFor { span, label, keyword_for, identifier, keyword_in, expr, stmt_block }
+    ASTStmtBreak { span, keyword_break, label, expr, token_semicolon }
+    ASTStmtContinue { span, keyword_continue, label, token_semicolon }
+    ASTStmtReturn { span, keyword_return, expr, token_semicolon }
+    ASTStmtAssignment { span, operand_lhs, operator, operand_rhs, token_semicolon }
+    ASTStmtAssignmentOperator { span, token_operator }
+    ASTStmtExpr { span, expr, token_semicolon }
+    ASTStmt { span, kind }
+    ASTExpr { span, kind }
+    ASTExprRange { span, start, operator, end }
+    ASTExprRangeOperator { span, token_operator }
+    ASTExprBinary { span, operand_lhs, operator, operand_rhs }
+    ASTExprBinaryOperator { span, token_operator }
+    ASTExprAs { span, expr, keyword_as, ty }
+    ASTExprUnary { span, operator, operand_lhs }
+    ASTExprUnaryOperator { span, token_operator }
+    ASTExprCall { span, callee, token_paren_open, args, token_paren_close }
+    ASTExprCallCallee { span, expr }
+    ASTExprMember { span, expr, token_dot, member }
+    ASTExprMemberPositional { span }
+    ASTExprIndex { span, expr, token_bracket_open, index, token_bracket_close }
+    ASTExprParen { span, token_paren_open, expr, token_paren_close }
+    ASTExprTuple { span, token_paren_open, elements, token_paren_close }
+    ASTExprPath { span, path }
+    ASTExprLiteral { span }
+    ASTExprInterpolatedString { span, token_head, interpolations }
+    ASTExprInterpolatedStringInterpolation { span, expr, token_tail }
+    ASTExprStructLiteral { span, path, token_brace_open, fields, token_brace_close }
+    ASTExprStructLiteralField { span, identifier, token_colon, expr }
+    ASTExprArrayLiteral { span, token_bracket_open, elements, token_bracket_close }
+    ASTExprArrayRepeat { span, token_bracket_open, expr, token_semicolon, count, token_bracket_close }
+    ASTExprIf { span, keyword_if, expr, stmt_block, else_ifs, else_ }
+    ASTExprIfElseIf { span, keyword_else, keyword_if, expr, stmt_block }
+    ASTExprIfElse { span, keyword_else, stmt_block }
+    ASTExprLoop { span, label, keyword_loop, stmt_block }
+    ASTExprMatch { span, keyword_match, expr, token_brace_open, arms, token_brace_close }
+    ASTExprMatchArm { span, pattern, token_fat_arrow, body }
+    ASTExprClosure { span, keyword_fn, token_paren_open, params, token_paren_close, result, stmt_block }
+    ASTPattern { span, kind }
+    ASTPatternWildcard { span, keyword_underscore }
+    ASTPatternBinding { span, identifier }
+    ASTPatternLiteral { span, token_minus }
+    ASTPatternRange { span, start, operator, end }
+    ASTPatternPath { span, path }
+    ASTPatternStruct { span, path, token_brace_open, fields, token_brace_close }
+    ASTPatternStructField { span, identifier, pattern }
+    ASTPatternStructFieldPattern { span, token_colon, pattern }
+    ASTPatternTupleStruct { span, path, token_paren_open, fields, token_paren_close }
+    ASTPatternTuple { span, token_paren_open, items, token_paren_close }
+    ASTPatternArray { span, token_bracket_open, items, token_bracket_close }
+    ASTPath { span, segments }
+    ASTPathSegment { span, identifier, generic }
+    ASTTy { span, kind }
+    ASTTyParen { span, token_paren_open, ty, token_paren_close }
+    ASTTyTuple { span, token_paren_open, items, token_paren_close }
+    ASTTySpan { span, token_bracket_open, ty, token_bracket_close }
+    ASTTyArray { span, token_bracket_open, ty, token_semicolon, token_bracket_close }
+    ASTTyFnPointer { span, keyword_fn, token_paren_open, params, token_paren_close, result }
+}
+
+impl_shift_span_enum! {
+    ASTModuleItemKind { Use, AliasDef, ConstDef, StaticDef, ModuleDecl, ModuleDef, ExternBlock, FnDef, StructDef, EnumDef, InterfaceDef, ImplBlock }
+    ASTUsePathPrefixSegmentKind { Self_, Super_, Identifier }
+    ASTVisibilityScopeKind { Super_, Module, In }
+    ASTUsePathItemKind { All, Single, Group }
+    ASTExternBlockItemKind { PrototypeDef, FnDef, StructDef, ImplBlock }
+    ASTInterfaceDefItemKind { FnDecl }
+    ASTImplBlockItemKind { FnDef }
+    ASTStmtKind { Let, While, For, Break, Continue, Return, Assignment, Expr }
+    ASTExprKind { Range, Binary, As, Unary, Call, Member, Index, Paren, Tuple, Path, Literal, StructLiteral, ArrayLiteral, ArrayRepeat, Block, If, Loop, Match, Closure, InterpolatedString }
+    ASTExprMatchArmBody { Block, Expr }
+    ASTExprMemberKind { Named, Positional }
+    ASTPatternKind { Wildcard, Binding, Literal, Range, Path, Struct, TupleStruct, Tuple, Array }
+    ASTTyKind { Paren, Tuple, Span, Array, FnPointer, Path }
+}
@@ -1,24 +1,51 @@
+mod number;
 mod symbols;
+mod text_direction;
 mod token;
+mod token_interpolated_string_part;
 mod token_kind;
 mod token_literal;
 mod token_literal_kind;
+mod token_literal_value;
+mod unescape;
+mod unknown_character;
 
+pub use number::*;
 pub use symbols::*;
+pub use text_direction::*;
 pub use token::*;
+pub use token_interpolated_string_part::*;
 pub use token_kind::*;
 pub use token_literal::*;
 pub use token_literal_kind::*;
+pub use token_literal_value::*;
+pub use unescape::*;
+pub use unknown_character::*;
 
 use crate::{
-    low_token_iter, LowToken, LowTokenKind, LowTokenLiteralKind, LowTokenNumberLiteralKind,
+    find_interpolation_opening, low_token_iter, LowToken, LowTokenInterpolatedStringPart,
+    LowTokenKind, LowTokenLiteralKind, LowTokenNumberLiteralKind,
 };
+use exc_diagnostic::{error_codes, DiagnosticsSender};
 use exc_span::{Pos, SourceFile, Span};
 use exc_symbol::Symbol;
 use std::iter::from_fn as iter_from_fn;
 
-pub fn token_iter(file: &SourceFile) -> impl Iterator<Item = Token> + '_ {
-    let mut iter = unglued_token_iter(file);
+pub fn token_iter<'a>(
+    file: &'a SourceFile,
+    diagnostics: &'a DiagnosticsSender,
+) -> impl Iterator<Item = Token> + 'a {
+    token_iter_in_span(file, file.span(), diagnostics)
+}
+
+/// Lexes only the given part of the file.
+/// The span must start at a position where no token is open, e.g. at the start of a module item.
+pub fn token_iter_in_span<'a>(
+    file: &'a SourceFile,
+    span: Span,
+    diagnostics: &'a DiagnosticsSender,
+) -> impl Iterator<Item = Token> + 'a {
+    let mut iter = unglued_token_iter(file, span, diagnostics);
     let mut current = iter.next();
     let mut next = iter.next();
 
@@ -44,32 +71,85 @@ pub fn token_iter(file: &SourceFile) -> impl Iterator<Item = Token> + '_ {
     })
 }
 
-fn unglued_token_iter(file: &SourceFile) -> impl Iterator<Item = Token> + '_ {
-    let mut low = file.span().low;
-    let mut iter = low_token_iter(file.content());
+fn unglued_token_iter<'a>(
+    file: &'a SourceFile,
+    span: Span,
+    diagnostics: &'a DiagnosticsSender,
+) -> impl Iterator<Item = Token> + 'a {
+    let content = file.slice(span);
+    let mut low = span.low;
+    let mut offset = 0;
+    let mut iter = low_token_iter(content).peekable();
 
-    iter_from_fn(move || loop {
-        let token = match iter.next() {
-            Some(token) => token,
-            None => return None,
+    iter_from_fn(move || {
+        let mut token = iter.next()?;
+
+        // Runs of unknown characters are reported as a single token,
+        // except replaceable ones that are reported and recovered from one by one.
+        let is_unknown = |token: &LowToken, offset: usize| {
+            token.kind == LowTokenKind::Unknown
+                && !content[offset..]
+                    .chars()
+                    .next()
+                    .map_or(false, is_replaceable_character)
         };
+
+        if is_unknown(&token, offset) {
+            while let Some(next) =
+                iter.next_if(|next| is_unknown(next, offset + token.len as usize))
+            {
+                token.len += next.len;
+            }
+        }
+
         let length = token.len;
-        let token = convert(token, low, file);
+        let token = convert(token, low, file, diagnostics);
 
         low += length;
+        offset += length as usize;
 
-        return Some(token);
+        Some(token)
     })
 }
 
-fn convert(token: LowToken, low: Pos, file: &SourceFile) -> Token {
+fn convert(token: LowToken, low: Pos, file: &SourceFile, diagnostics: &DiagnosticsSender) -> Token {
     let span = Span::new(low, low + token.len);
+
+    match token.kind {
+        LowTokenKind::Comment | LowTokenKind::BlockComment { .. } | LowTokenKind::DocComment => {
+            check_text_direction_codepoints(file.slice(span), span.low, "comment", diagnostics);
+        }
+        LowTokenKind::Literal { .. } | LowTokenKind::InterpolatedString { .. } => {
+            check_text_direction_codepoints(file.slice(span), span.low, "literal", diagnostics);
+        }
+        _ => {}
+    }
+
     let kind = match token.kind {
-        LowTokenKind::Unknown => TokenKind::Unknown {
-            symbol: Symbol::from_str(file.slice(span)),
-        },
+        LowTokenKind::Unknown => report_unknown_characters(file.slice(span), span, diagnostics)
+            .unwrap_or_else(|| TokenKind::Unknown {
+                symbol: Symbol::from_str(file.slice(span)),
+            }),
         LowTokenKind::Whitespace => TokenKind::Whitespace,
         LowTokenKind::Comment => TokenKind::Comment,
+        LowTokenKind::BlockComment { terminated } => {
+            if !terminated {
+                diagnostics.error_sub(
+                    error_codes::UNTERMINATED_BLOCK_COMMENT,
+                    Span::new(span.low, span.low + 2),
+                    format!("unterminated block comment"),
+                    vec![diagnostics.sub_hint(
+                        Span::new(span.high, span.high),
+                        format!("block comment is cut off here; expected `]#`"),
+                    )],
+                );
+            }
+
+            TokenKind::Comment
+        }
+        LowTokenKind::DocComment => TokenKind::DocComment {
+            symbol: Symbol::from_str(&file.slice(span)[2..]),
+        },
         LowTokenKind::OpenParen => TokenKind::OpenParen,
         LowTokenKind::CloseParen => TokenKind::CloseParen,
         LowTokenKind::OpenBrace => TokenKind::OpenBrace,
@@ -93,21 +173,27 @@ fn convert(token: LowToken, low: Pos, file: &SourceFile) -> Token {
         LowTokenKind::And => TokenKind::BitAnd,
         LowTokenKind::Caret => TokenKind::BitXor,
         LowTokenKind::Tilde => TokenKind::BitNot,
+        LowTokenKind::At => TokenKind::At,
         LowTokenKind::Id => match file.slice(span) {
             "true" => TokenKind::Literal(TokenLiteral::new(
                 TokenLiteralKind::Bool,
                 Symbol::from_str("true"),
                 None,
+                Some(TokenLiteralValue::Bool(true)),
             )),
             "false" => TokenKind::Literal(TokenLiteral::new(
                 TokenLiteralKind::Bool,
                 Symbol::from_str("false"),
                 None,
+                Some(TokenLiteralValue::Bool(false)),
             )),
             id => TokenKind::Id {
                 symbol: Symbol::from_str(id),
             },
         },
+        LowTokenKind::Label => TokenKind::Label {
+            symbol: Symbol::from_str(file.slice(span)),
+        },
         LowTokenKind::Literal { kind } => {
             let content = file.slice(span);
             let literal = match kind {
@@ -130,22 +216,190 @@ fn convert(token: LowToken, low: Pos, file: &SourceFile) -> Token {
                     } else {
                         Some(Symbol::from_str(&content[suffix_start..]))
                     };
-                    TokenLiteral::new(kind, non_prefix_content, suffix)
+                    let value = cook_number_literal(
+                        kind,
+                        &content[..suffix_start],
+                        suffix,
+                        span.low,
+                        diagnostics,
+                    );
+                    TokenLiteral::new(kind, non_prefix_content, suffix, value)
                 }
-                LowTokenLiteralKind::Character { terminated } => TokenLiteral::new(
-                    TokenLiteralKind::Character { terminated },
-                    Symbol::from_str(content),
-                    None,
-                ),
-                LowTokenLiteralKind::String { terminated } => TokenLiteral::new(
+                LowTokenLiteralKind::Character { terminated } => {
+                    let value = if terminated {
+                        let body = literal_body(content, terminated);
+                        cook_character_literal(body, span.low + 1, span, diagnostics)
+                            .map(TokenLiteralValue::Character)
+                    } else {
+                        report_unterminated_literal(span, "character", 1, "'", diagnostics);
+                        None
+                    };
+                    TokenLiteral::new(
+                        TokenLiteralKind::Character { terminated },
+                        Symbol::from_str(content),
+                        None,
+                        value,
+                    )
+                }
+                LowTokenLiteralKind::String { terminated } => {
+                    if !terminated {
+                        report_unterminated_literal(span, "string", 1, "\"", diagnostics);
+                    }
+
+                    let body = literal_body(content, terminated);
+                    let value = cook_string_literal(body, span.low + 1, diagnostics)
+                        .map(TokenLiteralValue::String);
+                    TokenLiteral::new(
+                        TokenLiteralKind::String { terminated },
+                        Symbol::from_str(content),
+                        None,
+                        value,
+                    )
+                }
+                LowTokenLiteralKind::RawString { hashes, terminated } => {
+                    let opening = hashes as usize + 2;
+                    let closing = format!("\"{}", "#".repeat(hashes as usize));
+
+                    if !terminated {
+                        report_unterminated_literal(
+                            span,
+                            "raw string",
+                            opening as u32,
+                            &closing,
+                            diagnostics,
+                        );
+                    }
+
+                    // Raw strings have no escape sequences; the body is the value as-is.
+                    let body = if terminated {
+                        &content[opening..content.len() - closing.len()]
+                    } else {
+                        &content[opening..]
+                    };
+                    TokenLiteral::new(
+                        TokenLiteralKind::RawString { hashes, terminated },
+                        Symbol::from_str(content),
+                        None,
+                        Some(TokenLiteralValue::String(Symbol::from_str(body))),
+                    )
+                }
+                LowTokenLiteralKind::MultiLineString { terminated } => {
+                    if !terminated {
+                        report_unterminated_literal(
+                            span,
+                            "multi-line string",
+                            3,
+                            "\"\"\"",
+                            diagnostics,
+                        );
+                    }
+
+                    let body = if terminated {
+                        &content[3..content.len() - 3]
+                    } else {
+                        &content[3..]
+                    };
+                    let value = cook_multi_line_string_literal(body, span.low + 3, diagnostics)
+                        .map(TokenLiteralValue::String);
+                    TokenLiteral::new(
+                        TokenLiteralKind::MultiLineString { terminated },
+                        Symbol::from_str(content),
+                        None,
+                        value,
+                    )
+                }
+            };
+            TokenKind::Literal(literal)
+        }
+        LowTokenKind::InterpolatedString { part, terminated } => {
+            let content = file.slice(span);
+            let (part, opening) = match part {
+                LowTokenInterpolatedStringPart::Whole => (TokenInterpolatedStringPart::Whole, 2),
+                LowTokenInterpolatedStringPart::Head => (TokenInterpolatedStringPart::Head, 2),
+                LowTokenInterpolatedStringPart::Middle => (TokenInterpolatedStringPart::Middle, 1),
+                LowTokenInterpolatedStringPart::Tail => (TokenInterpolatedStringPart::Tail, 1),
+            };
+
+            // Every part but an unterminated one ends with either `"` or `{`.
+            let body = if terminated {
+                &content[opening as usize..content.len() - 1]
+            } else {
+                let body = &content[opening as usize..];
+
+                // the part is cut off at the end of the line, either in an interpolation or in the text
+                match find_interpolation_opening(body) {
+                    Some(offset) => {
+                        let low = span.low + opening + offset;
+                        report_unterminated_interpolation(
+                            Span::new(low, low + 1),
+                            Span::new(span.high, span.high),
+                            diagnostics,
+                        );
+                        &body[..offset as usize]
+                    }
+                    None => {
+                        report_unterminated_literal(
+                            span,
+                            "interpolated string",
+                            opening,
+                            "\"",
+                            diagnostics,
+                        );
+                        body
+                    }
+                }
+            };
+            let value = cook_interpolated_string_part(body, span.low + opening, diagnostics)
+                .map(TokenLiteralValue::String);
+            TokenKind::InterpolatedString {
+                part,
+                literal: TokenLiteral::new(
                     TokenLiteralKind::String { terminated },
-                    Symbol::from_str(content),
+                    Symbol::from_str(body),
                     None,
+                    value,
                 ),
-            };
-            TokenKind::Literal(literal)
+            }
         }
     };
 
     Token::new(span, kind)
 }
+
+/// Reports an interpolation that is not closed before the end of its line.
+fn report_unterminated_interpolation(opening: Span, cut: Span, diagnostics: &DiagnosticsSender) {
+    diagnostics.error_sub(
+        error_codes::UNTERMINATED_INTERPOLATION,
+        opening,
+        format!("unterminated interpolation in interpolated string literal"),
+        vec![diagnostics.sub_hint(cut, format!("interpolation is cut off here; expected `}}`"))],
+    );
+}
+
+/// Strips the quotes from the content of a quoted literal.
+fn literal_body(content: &str, terminated: bool) -> &str {
+    if terminated {
+        &content[1..content.len() - 1]
+    } else {
+        &content[1..]
+    }
+}
+
+/// Reports an unterminated literal at its opening delimiter, which is `opening` bytes long.
+fn report_unterminated_literal(
+    span: Span,
+    name: &str,
+    opening: u32,
+    closing: &str,
+    diagnostics: &DiagnosticsSender,
+) {
+    diagnostics.error_sub(
+        error_codes::UNTERMINATED_LITERAL,
+        Span::new(span.low, span.low + opening),
+        format!("unterminated {} literal", name),
+        vec![diagnostics.sub_hint(
+            Span::new(span.high, span.high),
+            format!("{} literal is cut off here; expected `{}`", name, closing),
+        )],
+    );
+}
@@ -0,0 +1,248 @@
+use crate::{
+    TokenLiteralKind, TokenLiteralValue, TYPENAME_F32, TYPENAME_F64, TYPENAME_I16, TYPENAME_I32,
+    TYPENAME_I64, TYPENAME_I8, TYPENAME_U16, TYPENAME_U32, TYPENAME_U64, TYPENAME_U8,
+};
+use exc_diagnostic::{error_codes, DiagnosticsSender};
+use exc_span::{Pos, Span};
+use exc_symbol::Symbol;
+
+/// Validates the digits and the suffix of a number literal and computes its value.
+/// `content` is the literal without the suffix, starting at `low`.
+/// Returns `None` if the literal is malformed.
+pub fn cook_number_literal(
+    kind: TokenLiteralKind,
+    content: &str,
+    suffix: Option<Symbol>,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+) -> Option<TokenLiteralValue> {
+    let span = Span::new(low, low + content.len() as u32);
+    let suffix_span = Span::new(
+        span.high,
+        span.high + suffix.map_or(0, |suffix| suffix.to_str().len() as u32),
+    );
+    let suffix = match suffix {
+        Some(suffix) => match NumberSuffix::from_symbol(suffix) {
+            Some(suffix) => Some(suffix),
+            None => {
+                diagnostics.error_sub(
+                    error_codes::INVALID_LITERAL_SUFFIX,
+                    suffix_span,
+                    format!("invalid suffix {} for number literal", suffix),
+                    vec![diagnostics.sub_hint_simple(format!(
+                        "valid suffixes are `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32`, `u64`, `f32` and `f64`"
+                    ))],
+                );
+                return None;
+            }
+        },
+        None => None,
+    };
+
+    let (radix, prefix_len, name) = match kind {
+        TokenLiteralKind::IntegerBinary => (2, 2, "binary"),
+        TokenLiteralKind::IntegerOctal => (8, 2, "octal"),
+        TokenLiteralKind::IntegerHexadecimal => (16, 2, "hexadecimal"),
+        TokenLiteralKind::IntegerDecimal => (10, 0, "decimal"),
+        TokenLiteralKind::Float => {
+            return cook_float(content, suffix, span, suffix_span, diagnostics)
+        }
+        _ => return None,
+    };
+
+    if let Some(suffix @ (NumberSuffix::F32 | NumberSuffix::F64)) = suffix {
+        if radix == 10 {
+            return cook_float(content, Some(suffix), span, suffix_span, diagnostics);
+        }
+
+        diagnostics.error(
+            error_codes::INVALID_LITERAL_SUFFIX,
+            suffix_span,
+            format!(
+                "float suffix `{}` is not allowed on {} literal",
+                suffix.name(),
+                name
+            ),
+        );
+        return None;
+    }
+
+    let mut value = 0u64;
+    let mut has_digits = false;
+    let mut is_overflowed = false;
+
+    for (offset, char) in content.char_indices().skip(prefix_len) {
+        if char == '_' {
+            continue;
+        }
+
+        let digit = match char.to_digit(radix) {
+            Some(digit) => digit,
+            None => {
+                let offset = low + offset as u32;
+                diagnostics.error(
+                    error_codes::INVALID_DIGIT,
+                    Span::new(offset, offset + char.len_utf8() as u32),
+                    format!("invalid digit `{}` for {} literal", char, name),
+                );
+                return None;
+            }
+        };
+
+        has_digits = true;
+
+        match value
+            .checked_mul(radix as u64)
+            .and_then(|value| value.checked_add(digit as u64))
+        {
+            Some(next) => value = next,
+            None => is_overflowed = true,
+        }
+    }
+
+    if !has_digits {
+        diagnostics.error(
+            error_codes::INVALID_DIGIT,
+            span,
+            format!("{} literal has no digits", name),
+        );
+        return None;
+    }
+
+    let max = suffix.map_or(u64::MAX, |suffix| suffix.max_integer());
+
+    if is_overflowed || max < value {
+        diagnostics.error(
+            error_codes::LITERAL_OUT_OF_RANGE,
+            span.to(suffix_span),
+            match suffix {
+                Some(suffix) => format!(
+                    "integer literal is out of range for `{}`; the maximum is `{}`",
+                    suffix.name(),
+                    max
+                ),
+                None => format!("integer literal is too large; the maximum is `{}`", max),
+            },
+        );
+        return None;
+    }
+
+    Some(TokenLiteralValue::Integer(value))
+}
+
+fn cook_float(
+    content: &str,
+    suffix: Option<NumberSuffix>,
+    span: Span,
+    suffix_span: Span,
+    diagnostics: &DiagnosticsSender,
+) -> Option<TokenLiteralValue> {
+    match suffix {
+        Some(NumberSuffix::F32 | NumberSuffix::F64) | None => {}
+        Some(suffix) => {
+            diagnostics.error(
+                error_codes::INVALID_LITERAL_SUFFIX,
+                suffix_span,
+                format!(
+                    "integer suffix `{}` is not allowed on float literal",
+                    suffix.name()
+                ),
+            );
+            return None;
+        }
+    }
+
+    let digits = content.replace('_', "");
+    let value = match digits.parse::<f64>() {
+        Ok(value) => value,
+        Err(_) => {
+            diagnostics.error(
+                error_codes::INVALID_DIGIT,
+                span,
+                format!("invalid float literal"),
+            );
+            return None;
+        }
+    };
+
+    let is_overflowed = match suffix {
+        Some(NumberSuffix::F32) => (value as f32).is_infinite(),
+        _ => value.is_infinite(),
+    };
+
+    if is_overflowed {
+        diagnostics.error(
+            error_codes::LITERAL_OUT_OF_RANGE,
+            span.to(suffix_span),
+            format!(
+                "float literal is out of range for `{}`",
+                suffix.map_or("f64", |suffix| suffix.name())
+            ),
+        );
+        return None;
+    }
+
+    Some(TokenLiteralValue::Float(value.to_bits()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumberSuffix {
+    I8,
+    I16,
+    I32,
+    I64,
+    U8,
+    U16,
+    U32,
+    U64,
+    F32,
+    F64,
+}
+
+impl NumberSuffix {
+    fn from_symbol(symbol: Symbol) -> Option<Self> {
+        Some(match symbol {
+            symbol if symbol == *TYPENAME_I8 => Self::I8,
+            symbol if symbol == *TYPENAME_I16 => Self::I16,
+            symbol if symbol == *TYPENAME_I32 => Self::I32,
+            symbol if symbol == *TYPENAME_I64 => Self::I64,
+            symbol if symbol == *TYPENAME_U8 => Self::U8,
+            symbol if symbol == *TYPENAME_U16 => Self::U16,
+            symbol if symbol == *TYPENAME_U32 => Self::U32,
+            symbol if symbol == *TYPENAME_U64 => Self::U64,
+            symbol if symbol == *TYPENAME_F32 => Self::F32,
+            symbol if symbol == *TYPENAME_F64 => Self::F64,
+            _ => return None,
+        })
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::I8 => "i8",
+            Self::I16 => "i16",
+            Self::I32 => "i32",
+            Self::I64 => "i64",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::U32 => "u32",
+            Self::U64 => "u64",
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+
+    /// Largest literal value for the integer suffix.
+    /// Signed ones allow the magnitude of their minimum, as the literal may be negated e.g. `-128i8`.
+    fn max_integer(self) -> u64 {
+        match self {
+            Self::I8 => i8::MIN.unsigned_abs() as u64,
+            Self::I16 => i16::MIN.unsigned_abs() as u64,
+            Self::I32 => i32::MIN.unsigned_abs() as u64,
+            Self::I64 => i64::MIN.unsigned_abs(),
+            Self::U8 => u8::MAX as u64,
+            Self::U16 => u16::MAX as u64,
+            Self::U32 => u32::MAX as u64,
+            Self::U64 | Self::F32 | Self::F64 => u64::MAX,
+        }
+    }
+}
@@ -5,6 +5,7 @@ lazy_static! {
     pub static ref UNKNOWN: Symbol = Symbol::from_str("unknown");
     pub static ref WHITESPACE: Symbol = Symbol::from_str("whitespace");
     pub static ref COMMENT: Symbol = Symbol::from_str("comment");
+    pub static ref DOC_COMMENT: Symbol = Symbol::from_str("doc comment");
     pub static ref OPEN_PAREN: Symbol = Symbol::from_str("(");
     pub static ref CLOSE_PAREN: Symbol = Symbol::from_str(")");
     pub static ref OPEN_BRACE: Symbol = Symbol::from_str("{");
@@ -16,6 +17,8 @@ lazy_static! {
     pub static ref COLON: Symbol = Symbol::from_str(":");
     pub static ref SEMICOLON: Symbol = Symbol::from_str(";");
     pub static ref ARROW: Symbol = Symbol::from_str("->");
+    pub static ref FAT_ARROW: Symbol = Symbol::from_str("=>");
+    pub static ref AT: Symbol = Symbol::from_str("@");
 }
 
 lazy_static! {
@@ -34,6 +37,7 @@ lazy_static! {
     pub static ref ASSIGN_BIT_NOT: Symbol = Symbol::from_str("~=");
     pub static ref RNG: Symbol = Symbol::from_str("..");
     pub static ref RNG_INCLUSIVE: Symbol = Symbol::from_str("..=");
+    pub static ref ELLIPSIS: Symbol = Symbol::from_str("...");
     pub static ref EQ: Symbol = Symbol::from_str("==");
     pub static ref NE: Symbol = Symbol::from_str("!=");
     pub static ref LT: Symbol = Symbol::from_str("<");
@@ -57,7 +61,9 @@ lazy_static! {
     pub static ref LOG_NOT: Symbol = Symbol::from_str("!");
     pub static ref PATH_SEP: Symbol = Symbol::from_str("::");
     pub static ref ID: Symbol = Symbol::from_str("identifier");
+    pub static ref LABEL: Symbol = Symbol::from_str("label");
     pub static ref LITERAL: Symbol = Symbol::from_str("literal");
+    pub static ref INTERPOLATED_STRING: Symbol = Symbol::from_str("interpolated string");
 }
 
 lazy_static! {
@@ -65,11 +71,14 @@ lazy_static! {
     pub static ref KEYWORD_SELF: Symbol = Symbol::from_str("self");
     pub static ref KEYWORD_SUPER: Symbol = Symbol::from_str("super");
     pub static ref KEYWORD_ALIAS: Symbol = Symbol::from_str("alias");
+    pub static ref KEYWORD_CONST: Symbol = Symbol::from_str("const");
+    pub static ref KEYWORD_STATIC: Symbol = Symbol::from_str("static");
     pub static ref KEYWORD_MODULE: Symbol = Symbol::from_str("module");
     pub static ref KEYWORD_EXTERN: Symbol = Symbol::from_str("extern");
     pub static ref KEYWORD_PROTOTYPE: Symbol = Symbol::from_str("prototype");
     pub static ref KEYWORD_FN: Symbol = Symbol::from_str("fn");
     pub static ref KEYWORD_STRUCT: Symbol = Symbol::from_str("struct");
+    pub static ref KEYWORD_ENUM: Symbol = Symbol::from_str("enum");
     pub static ref KEYWORD_INTERFACE: Symbol = Symbol::from_str("interface");
     pub static ref KEYWORD_IMPL: Symbol = Symbol::from_str("impl");
     pub static ref KEYWORD_PUB: Symbol = Symbol::from_str("pub");
@@ -79,9 +88,13 @@ lazy_static! {
     pub static ref KEYWORD_ELSE: Symbol = Symbol::from_str("else");
     pub static ref KEYWORD_LOOP: Symbol = Symbol::from_str("loop");
     pub static ref KEYWORD_WHILE: Symbol = Symbol::from_str("while");
+    pub static ref KEYWORD_FOR: Symbol = Symbol::from_str("for");
+    pub static ref KEYWORD_IN: Symbol = Symbol::from_str("in");
     pub static ref KEYWORD_BREAK: Symbol = Symbol::from_str("break");
     pub static ref KEYWORD_CONTINUE: Symbol = Symbol::from_str("continue");
     pub static ref KEYWORD_RETURN: Symbol = Symbol::from_str("return");
+    pub static ref KEYWORD_MATCH: Symbol = Symbol::from_str("match");
+    pub static ref KEYWORD_UNDERSCORE: Symbol = Symbol::from_str("_");
     pub static ref KEYWORD_AS: Symbol = Symbol::from_str("as");
 }
 
@@ -92,4 +105,24 @@ lazy_static! {
     pub static ref TYPENAME_STRING: Symbol = Symbol::from_str("string");
     pub static ref TYPENAME_PTR: Symbol = Symbol::from_str("ptr");
     pub static ref TYPENAME_REF: Symbol = Symbol::from_str("ref");
+    pub static ref TYPENAME_I8: Symbol = Symbol::from_str("i8");
+    pub static ref TYPENAME_I16: Symbol = Symbol::from_str("i16");
+    pub static ref TYPENAME_I32: Symbol = Symbol::from_str("i32");
+    pub static ref TYPENAME_I64: Symbol = Symbol::from_str("i64");
+    pub static ref TYPENAME_U8: Symbol = Symbol::from_str("u8");
+    pub static ref TYPENAME_U16: Symbol = Symbol::from_str("u16");
+    pub static ref TYPENAME_U32: Symbol = Symbol::from_str("u32");
+    pub static ref TYPENAME_U64: Symbol = Symbol::from_str("u64");
+    pub static ref TYPENAME_F32: Symbol = Symbol::from_str("f32");
+    pub static ref TYPENAME_F64: Symbol = Symbol::from_str("f64");
+}
+
+lazy_static! {
+    pub static ref ATTRIBUTE_INLINE: Symbol = Symbol::from_str("inline");
+    pub static ref ATTRIBUTE_TEST: Symbol = Symbol::from_str("test");
+    pub static ref ATTRIBUTE_DEPRECATED: Symbol = Symbol::from_str("deprecated");
+    pub static ref ATTRIBUTE_LINK_NAME: Symbol = Symbol::from_str("link_name");
+    pub static ref ATTRIBUTE_ALLOW: Symbol = Symbol::from_str("allow");
+    pub static ref ATTRIBUTE_WARN: Symbol = Symbol::from_str("warn");
+    pub static ref ATTRIBUTE_DENY: Symbol = Symbol::from_str("deny");
 }
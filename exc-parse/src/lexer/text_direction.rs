@@ -0,0 +1,67 @@
+use exc_diagnostic::{warning_codes, DiagnosticsSender};
+use exc_span::{Pos, Span};
+
+/// Unicode codepoints that change the visual order of the text around them,
+/// which can make the source look different from what the lexer sees.
+const TEXT_DIRECTION_CODEPOINTS: [(char, &str); 9] = [
+    ('\u{202A}', "LEFT-TO-RIGHT EMBEDDING"),
+    ('\u{202B}', "RIGHT-TO-LEFT EMBEDDING"),
+    ('\u{202C}', "POP DIRECTIONAL FORMATTING"),
+    ('\u{202D}', "LEFT-TO-RIGHT OVERRIDE"),
+    ('\u{202E}', "RIGHT-TO-LEFT OVERRIDE"),
+    ('\u{2066}', "LEFT-TO-RIGHT ISOLATE"),
+    ('\u{2067}', "RIGHT-TO-LEFT ISOLATE"),
+    ('\u{2068}', "FIRST STRONG ISOLATE"),
+    ('\u{2069}', "POP DIRECTIONAL ISOLATE"),
+];
+
+/// Warns about text direction codepoints in the content of a comment or a literal.
+pub fn check_text_direction_codepoints(
+    content: &str,
+    low: Pos,
+    name: &str,
+    diagnostics: &DiagnosticsSender,
+) {
+    let codepoints = content
+        .char_indices()
+        .filter_map(|(offset, char)| {
+            let (_, codepoint_name) = TEXT_DIRECTION_CODEPOINTS
+                .iter()
+                .find(|(codepoint, _)| *codepoint == char)?;
+            let low = low + offset as u32;
+            Some((
+                Span::new(low, low + char.len_utf8() as u32),
+                char,
+                *codepoint_name,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    let span = match codepoints.first() {
+        Some((span, _, _)) => *span,
+        None => return,
+    };
+
+    let mut sub_diagnostics = codepoints
+        .iter()
+        .map(|(span, char, codepoint_name)| {
+            diagnostics.sub_hint(
+                *span,
+                format!("this is `U+{:04X}` ({})", *char as u32, codepoint_name),
+            )
+        })
+        .collect::<Vec<_>>();
+    sub_diagnostics.push(diagnostics.sub_hint_simple(format!(
+        "these codepoints may make the code look different from how it is compiled; remove them if they are not intended"
+    )));
+
+    diagnostics.warning_sub(
+        warning_codes::TEXT_DIRECTION_CODEPOINT,
+        span,
+        format!(
+            "unicode codepoint changing the visible direction of text present in {}",
+            name
+        ),
+        sub_diagnostics,
+    );
+}
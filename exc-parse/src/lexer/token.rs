@@ -25,10 +25,12 @@ impl Token {
             },
             TokenKind::Rng => match next.kind {
                 TokenKind::Assign => TokenKind::RngInclusive,
+                TokenKind::Dot => TokenKind::Ellipsis,
                 _ => return None,
             },
             TokenKind::Assign => match next.kind {
                 TokenKind::Assign => TokenKind::Eq,
+                TokenKind::Gt => TokenKind::FatArrow,
                 _ => return None,
             },
             TokenKind::Lt => match next.kind {
@@ -96,6 +98,7 @@ impl Token {
             TokenKind::Unknown { .. }
             | TokenKind::Whitespace
             | TokenKind::Comment
+            | TokenKind::DocComment { .. }
             | TokenKind::OpenParen
             | TokenKind::CloseParen
             | TokenKind::OpenBrace
@@ -105,6 +108,8 @@ impl Token {
             | TokenKind::Comma
             | TokenKind::Semicolon
             | TokenKind::Arrow
+            | TokenKind::FatArrow
+            | TokenKind::At
             | TokenKind::AssignAdd
             | TokenKind::AssignSub
             | TokenKind::AssignMul
@@ -117,6 +122,7 @@ impl Token {
             | TokenKind::AssignBitAnd
             | TokenKind::AssignBitXor
             | TokenKind::RngInclusive
+            | TokenKind::Ellipsis
             | TokenKind::Eq
             | TokenKind::Ne
             | TokenKind::Le
@@ -126,7 +132,9 @@ impl Token {
             | TokenKind::LogAnd
             | TokenKind::PathSep
             | TokenKind::Literal { .. }
-            | TokenKind::Id { .. } => return None,
+            | TokenKind::InterpolatedString { .. }
+            | TokenKind::Id { .. }
+            | TokenKind::Label { .. } => return None,
         };
 
         Some(Token::new(self.span.to(next.span), kind))
@@ -280,6 +288,20 @@ impl Token {
                     kind: TokenKind::Assign,
                 });
             }
+            TokenKind::Ellipsis => {
+                unglued_tokens.push_back(Self {
+                    span: Span::new(self.span.low, self.span.low + 1),
+                    kind: TokenKind::Dot,
+                });
+                unglued_tokens.push_back(Self {
+                    span: Span::new(self.span.low + 1, self.span.low + 2),
+                    kind: TokenKind::Dot,
+                });
+                unglued_tokens.push_back(Self {
+                    span: Span::new(self.span.low + 2, self.span.low + 3),
+                    kind: TokenKind::Dot,
+                });
+            }
             TokenKind::Eq => {
                 unglued_tokens.push_back(Self {
                     span: Span::new(self.span.low, self.span.low + 1),
@@ -373,6 +395,7 @@ impl Token {
             TokenKind::Unknown { .. }
             | TokenKind::Whitespace
             | TokenKind::Comment
+            | TokenKind::DocComment { .. }
             | TokenKind::OpenParen
             | TokenKind::CloseParen
             | TokenKind::OpenBrace
@@ -384,6 +407,8 @@ impl Token {
             | TokenKind::Colon
             | TokenKind::Semicolon
             | TokenKind::Arrow
+            | TokenKind::FatArrow
+            | TokenKind::At
             | TokenKind::Assign
             | TokenKind::Lt
             | TokenKind::Gt
@@ -399,7 +424,9 @@ impl Token {
             | TokenKind::LogNot
             | TokenKind::PathSep
             | TokenKind::Id { .. }
-            | TokenKind::Literal(_) => unglued_tokens.push_back(self),
+            | TokenKind::Label { .. }
+            | TokenKind::Literal(_)
+            | TokenKind::InterpolatedString { .. } => unglued_tokens.push_back(self),
         }
     }
 }
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenInterpolatedStringPart {
+    Whole,  // f"..." without interpolations
+    Head,   // f"...{
+    Middle, // }...{
+    Tail,   // }..."
+}
+
+impl TokenInterpolatedStringPart {
+    /// Returns `true` if the part is the beginning of an interpolated string.
+    pub fn is_start(self) -> bool {
+        matches!(self, Self::Whole | Self::Head)
+    }
+
+    /// Returns `true` if the part is the end of an interpolated string.
+    pub fn is_end(self) -> bool {
+        matches!(self, Self::Whole | Self::Tail)
+    }
+}
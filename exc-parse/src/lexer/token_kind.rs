@@ -1,22 +1,29 @@
-use crate::TokenLiteral;
+use crate::{TokenInterpolatedStringPart, TokenLiteral};
 use exc_symbol::Symbol;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
-    Unknown { symbol: Symbol },
+    Unknown {
+        symbol: Symbol,
+    },
     Whitespace,
-    Comment,      // "#"
-    OpenParen,    // "("
-    CloseParen,   // ")"
-    OpenBrace,    // "{"
-    CloseBrace,   // "}"
-    OpenBracket,  // "["
+    Comment, // "#" or "#[ ... ]#"
+    DocComment {
+        symbol: Symbol,
+    }, // "##"
+    OpenParen, // "("
+    CloseParen, // ")"
+    OpenBrace, // "{"
+    CloseBrace, // "}"
+    OpenBracket, // "["
     CloseBracket, // "]"
-    Dot,          // "."
-    Comma,        // ","
-    Colon,        // ":"
-    Semicolon,    // ";"
-    Arrow,        // "->"
+    Dot,     // "."
+    Comma,   // ","
+    Colon,   // ":"
+    Semicolon, // ";"
+    Arrow,   // "->"
+    FatArrow, // "=>"
+    At,      // "@"
     // Assignment operators
     Assign,       // "="
     AssignAdd,    // "+="
@@ -33,6 +40,8 @@ pub enum TokenKind {
     // Range operators
     Rng,          // ".."
     RngInclusive, // "..="
+    // Variadic parameters
+    Ellipsis, // "..."
     // Cmp operators
     Eq, // "=="
     Ne, // "!="
@@ -59,8 +68,17 @@ pub enum TokenKind {
     LogNot, // "!"
     // Module access operators
     PathSep, // "::"
-    Id { symbol: Symbol },
+    Id {
+        symbol: Symbol,
+    },
+    Label {
+        symbol: Symbol,
+    }, // "'" followed by an identifier e.g. 'outer
     Literal(TokenLiteral),
+    InterpolatedString {
+        part: TokenInterpolatedStringPart,
+        literal: TokenLiteral,
+    },
 }
 
 impl TokenKind {
@@ -69,6 +87,7 @@ impl TokenKind {
             TokenKind::Unknown { .. } => *crate::UNKNOWN,
             TokenKind::Whitespace => *crate::WHITESPACE,
             TokenKind::Comment => *crate::COMMENT,
+            TokenKind::DocComment { .. } => *crate::DOC_COMMENT,
             TokenKind::OpenParen => *crate::OPEN_PAREN,
             TokenKind::CloseParen => *crate::CLOSE_PAREN,
             TokenKind::OpenBrace => *crate::OPEN_BRACE,
@@ -80,6 +99,8 @@ impl TokenKind {
             TokenKind::Colon => *crate::COLON,
             TokenKind::Semicolon => *crate::SEMICOLON,
             TokenKind::Arrow => *crate::ARROW,
+            TokenKind::FatArrow => *crate::FAT_ARROW,
+            TokenKind::At => *crate::AT,
             TokenKind::Assign => *crate::ASSIGN,
             TokenKind::AssignAdd => *crate::ASSIGN_ADD,
             TokenKind::AssignSub => *crate::ASSIGN_SUB,
@@ -94,6 +115,7 @@ impl TokenKind {
             TokenKind::AssignBitXor => *crate::ASSIGN_BIT_XOR,
             TokenKind::Rng => *crate::RNG,
             TokenKind::RngInclusive => *crate::RNG_INCLUSIVE,
+            TokenKind::Ellipsis => *crate::ELLIPSIS,
             TokenKind::Eq => *crate::EQ,
             TokenKind::Ne => *crate::NE,
             TokenKind::Lt => *crate::LT,
@@ -117,7 +139,9 @@ impl TokenKind {
             TokenKind::LogNot => *crate::LOG_NOT,
             TokenKind::PathSep => *crate::PATH_SEP,
             TokenKind::Id { .. } => *crate::ID,
+            TokenKind::Label { .. } => *crate::LABEL,
             TokenKind::Literal(..) => *crate::LITERAL,
+            TokenKind::InterpolatedString { .. } => *crate::INTERPOLATED_STRING,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::TokenLiteralKind;
+use crate::{TokenLiteralKind, TokenLiteralValue};
 use exc_symbol::Symbol;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -6,14 +6,22 @@ pub struct TokenLiteral {
     pub kind: TokenLiteralKind,
     pub content: Symbol,
     pub suffix: Option<Symbol>,
+    /// cooked value of the literal, `None` if the literal is malformed
+    pub value: Option<TokenLiteralValue>,
 }
 
 impl TokenLiteral {
-    pub fn new(kind: TokenLiteralKind, content: Symbol, suffix: Option<Symbol>) -> Self {
+    pub fn new(
+        kind: TokenLiteralKind,
+        content: Symbol,
+        suffix: Option<Symbol>,
+        value: Option<TokenLiteralValue>,
+    ) -> Self {
         Self {
             kind,
             content,
             suffix,
+            value,
         }
     }
 }
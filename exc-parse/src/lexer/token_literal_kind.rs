@@ -8,4 +8,6 @@ pub enum TokenLiteralKind {
     Float,
     Character { terminated: bool },
     String { terminated: bool },
+    RawString { hashes: u32, terminated: bool },
+    MultiLineString { terminated: bool },
 }
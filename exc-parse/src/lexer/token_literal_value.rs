@@ -0,0 +1,19 @@
+use exc_symbol::Symbol;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TokenLiteralValue {
+    Bool(bool),
+    Integer(u64),
+    Float(u64), // bits of the f64 value, see `f64::to_bits`
+    Character(char),
+    String(Symbol), // escape sequences are decoded
+}
+
+impl TokenLiteralValue {
+    pub fn as_f64(self) -> Option<f64> {
+        match self {
+            Self::Float(bits) => Some(f64::from_bits(bits)),
+            _ => None,
+        }
+    }
+}
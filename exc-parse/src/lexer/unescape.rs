@@ -0,0 +1,388 @@
+use exc_diagnostic::{error_codes, DiagnosticsSender};
+use exc_span::{Pos, Span};
+use exc_symbol::Symbol;
+use std::{iter::Peekable, str::CharIndices};
+
+/// Decodes the body (without the quotes) of a character literal.
+/// Returns `None` if the body contains invalid escape sequences or is not exactly one character.
+pub fn cook_character_literal(
+    body: &str,
+    low: Pos,
+    span: Span,
+    diagnostics: &DiagnosticsSender,
+) -> Option<char> {
+    let mut units = Vec::with_capacity(1);
+    unescape(body, low, diagnostics, |offset, char| {
+        units.push((offset, char))
+    });
+
+    match units.as_slice() {
+        [] => {
+            diagnostics.error(
+                error_codes::EMPTY_CHARACTER_LITERAL,
+                span,
+                format!("empty character literal"),
+            );
+            None
+        }
+        [(_, char)] => *char,
+        [_, (offset, _), ..] => {
+            diagnostics.error_sub(
+                error_codes::MULTIPLE_CHARACTERS_IN_CHARACTER_LITERAL,
+                span,
+                format!("character literal may only contain one character"),
+                vec![diagnostics.sub_hint(
+                    Span::new(low + *offset, low + body.len() as u32),
+                    format!("consider using a string literal if you want more than one character"),
+                )],
+            );
+            None
+        }
+    }
+}
+
+/// Decodes the body (without the quotes) of a string literal.
+/// Returns `None` if the body contains invalid escape sequences.
+pub fn cook_string_literal(
+    body: &str,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+) -> Option<Symbol> {
+    let mut content = String::with_capacity(body.len());
+    let mut is_valid = true;
+
+    unescape(body, low, diagnostics, |_, char| match char {
+        Some(char) => content.push(char),
+        None => is_valid = false,
+    });
+
+    if is_valid {
+        Some(Symbol::from_str(&content))
+    } else {
+        None
+    }
+}
+
+/// Decodes the body (without the quotes) of a multi-line string literal.
+/// The line break after the opening quotes and the line of the closing quotes are not part of the value,
+/// and the indentation common to all non-blank lines (including the closing line) is stripped.
+/// Returns `None` if the body contains invalid escape sequences.
+pub fn cook_multi_line_string_literal(
+    body: &str,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+) -> Option<Symbol> {
+    let mut lines = Vec::new();
+    let mut offset = 0;
+
+    for line in body.split_inclusive('\n') {
+        lines.push((offset, line));
+        offset += line.len();
+    }
+
+    let is_blank = |line: &str| line.trim().is_empty();
+    let indentation = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+
+    if let Some((_, line)) = lines.first() {
+        if line.ends_with('\n') && is_blank(line) {
+            lines.remove(0);
+        }
+    }
+
+    let closing_line = match lines.last() {
+        Some((_, line)) if !line.ends_with('\n') && is_blank(line) => lines.pop(),
+        _ => None,
+    };
+    let indent = lines
+        .iter()
+        .filter(|(_, line)| !is_blank(line))
+        .chain(closing_line.iter())
+        .map(|(_, line)| indentation(line))
+        .min()
+        .unwrap_or(0);
+
+    let mut content = String::with_capacity(body.len());
+    let mut is_valid = true;
+
+    for (index, (offset, line)) in lines.iter().enumerate() {
+        let mut line = *line;
+
+        // The line break before the closing line belongs to the closing quotes.
+        if closing_line.is_some() && index + 1 == lines.len() {
+            line = line.trim_end_matches('\n').trim_end_matches('\r');
+        }
+
+        if is_blank(line) {
+            content.push_str(&line[line.trim_end_matches(['\n', '\r']).len()..]);
+            continue;
+        }
+
+        unescape(
+            &line[indent..],
+            low + (offset + indent) as u32,
+            diagnostics,
+            |_, char| match char {
+                Some(char) => content.push(char),
+                None => is_valid = false,
+            },
+        );
+    }
+
+    if is_valid {
+        Some(Symbol::from_str(&content))
+    } else {
+        None
+    }
+}
+
+/// Decodes the body (without the quotes and braces) of a part of an interpolated string literal.
+/// `{{` and `}}` are decoded into single braces.
+/// Returns `None` if the body contains invalid escape sequences or a `}` that is not doubled.
+pub fn cook_interpolated_string_part(
+    body: &str,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+) -> Option<Symbol> {
+    let mut content = String::with_capacity(body.len());
+    let mut is_valid = true;
+    let mut is_escaped_brace = false;
+
+    unescape(body, low, diagnostics, |offset, char| {
+        if is_escaped_brace {
+            is_escaped_brace = false;
+            return;
+        }
+
+        match char {
+            Some(char @ ('{' | '}')) => {
+                // braces written by escape sequences (e.g. `\u{7B}`) are not doubled
+                let rest = &body[offset as usize..];
+                is_escaped_brace = rest.starts_with(char) && rest[1..].starts_with(char);
+
+                // a single `{` always opens an interpolation, so only a `}` can be left alone
+                if rest.starts_with('}') && !is_escaped_brace {
+                    let low = low + offset;
+                    diagnostics.error_sub(
+                        error_codes::UNMATCHED_CLOSING_BRACE,
+                        Span::new(low, low + 1),
+                        format!("unmatched `}}` in interpolated string literal"),
+                        vec![diagnostics.sub_hint(
+                            Span::new(low, low + 1),
+                            format!("use `}}}}` for a literal `}}`"),
+                        )],
+                    );
+                    is_valid = false;
+                }
+
+                content.push(char);
+            }
+            Some(char) => content.push(char),
+            None => is_valid = false,
+        }
+    });
+
+    if is_valid {
+        Some(Symbol::from_str(&content))
+    } else {
+        None
+    }
+}
+
+/// Calls `push` once for every character or escape sequence in `body`, along with its byte offset.
+/// Invalid escape sequences are reported and passed as `None`.
+fn unescape(
+    body: &str,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+    mut push: impl FnMut(u32, Option<char>),
+) {
+    let mut chars = body.char_indices().peekable();
+
+    while let Some((offset, char)) = chars.next() {
+        let char = if char == '\\' {
+            unescape_char(body, offset, &mut chars, low, diagnostics)
+        } else {
+            Some(char)
+        };
+
+        push(offset as u32, char);
+    }
+}
+
+fn unescape_char(
+    body: &str,
+    start: usize,
+    chars: &mut Peekable<CharIndices>,
+    low: Pos,
+    diagnostics: &DiagnosticsSender,
+) -> Option<char> {
+    let span_to = |chars: &mut Peekable<CharIndices>| {
+        let end = chars.peek().map_or(body.len(), |(offset, _)| *offset);
+        Span::new(low + start as u32, low + end as u32)
+    };
+
+    let char = match chars.next() {
+        Some((_, char)) => char,
+        None => {
+            diagnostics.error(
+                error_codes::INVALID_ESCAPE_SEQUENCE,
+                span_to(chars),
+                format!("expected an escape sequence, but found end of literal"),
+            );
+            return None;
+        }
+    };
+
+    match char {
+        'n' => Some('\n'),
+        'r' => Some('\r'),
+        't' => Some('\t'),
+        '0' => Some('\0'),
+        '\\' => Some('\\'),
+        '\'' => Some('\''),
+        '"' => Some('"'),
+        'x' => {
+            let mut value = 0;
+            let mut digits = 0;
+
+            while digits < 2 {
+                match chars.peek().and_then(|(_, char)| char.to_digit(16)) {
+                    Some(digit) => {
+                        chars.next();
+                        value = value * 16 + digit;
+                        digits += 1;
+                    }
+                    None => break,
+                }
+            }
+
+            if digits != 2 {
+                diagnostics.error(
+                    error_codes::INVALID_ESCAPE_SEQUENCE,
+                    span_to(chars),
+                    format!("hex escape must have exactly two hex digits"),
+                );
+                return None;
+            }
+
+            if 0x7f < value {
+                diagnostics.error(
+                    error_codes::INVALID_CODE_POINT,
+                    span_to(chars),
+                    format!("hex escape is out of range; it must be in range [\\x00, \\x7F]"),
+                );
+                return None;
+            }
+
+            char::from_u32(value)
+        }
+        'u' => {
+            match chars.peek() {
+                Some((_, '{')) => {
+                    chars.next();
+                }
+                _ => {
+                    diagnostics.error(
+                        error_codes::INVALID_ESCAPE_SEQUENCE,
+                        span_to(chars),
+                        format!("unicode escape must be in form of \\u{{...}}"),
+                    );
+                    return None;
+                }
+            }
+
+            let mut value = 0u32;
+            let mut digits = 0;
+
+            loop {
+                match chars.peek().copied() {
+                    Some((_, '}')) => {
+                        chars.next();
+                        break;
+                    }
+                    Some((_, char)) if char.is_ascii_hexdigit() => {
+                        chars.next();
+                        digits += 1;
+
+                        // keep counting the digits for the error below, but stop accumulating
+                        if digits <= 6 {
+                            value = value * 16 + char.to_digit(16).unwrap();
+                        }
+                    }
+                    Some((offset, char)) => {
+                        diagnostics.error_sub(
+                            error_codes::INVALID_ESCAPE_SEQUENCE,
+                            span_to(chars),
+                            format!(
+                                "invalid character `{}` in unicode escape",
+                                char.escape_default()
+                            ),
+                            vec![diagnostics.sub_hint(
+                                Span::new(
+                                    low + offset as u32,
+                                    low + (offset + char.len_utf8()) as u32,
+                                ),
+                                format!("only hex digits are allowed here"),
+                            )],
+                        );
+                        return None;
+                    }
+                    None => {
+                        diagnostics.error(
+                            error_codes::INVALID_ESCAPE_SEQUENCE,
+                            span_to(chars),
+                            format!("unicode escape is not terminated; expected `}}`"),
+                        );
+                        return None;
+                    }
+                }
+            }
+
+            if digits == 0 {
+                diagnostics.error(
+                    error_codes::INVALID_ESCAPE_SEQUENCE,
+                    span_to(chars),
+                    format!("unicode escape must have at least one hex digit"),
+                );
+                return None;
+            }
+
+            if 6 < digits {
+                diagnostics.error(
+                    error_codes::INVALID_ESCAPE_SEQUENCE,
+                    span_to(chars),
+                    format!("unicode escape must have at most 6 hex digits"),
+                );
+                return None;
+            }
+
+            match char::from_u32(value) {
+                Some(char) => Some(char),
+                None if (0xd800..=0xdfff).contains(&value) => {
+                    diagnostics.error(
+                        error_codes::INVALID_CODE_POINT,
+                        span_to(chars),
+                        format!("unicode escape must not be a surrogate"),
+                    );
+                    None
+                }
+                None => {
+                    diagnostics.error(
+                        error_codes::INVALID_CODE_POINT,
+                        span_to(chars),
+                        format!("unicode escape is out of range; it must be at most \\u{{10FFFF}}"),
+                    );
+                    None
+                }
+            }
+        }
+        char => {
+            diagnostics.error(
+                error_codes::INVALID_ESCAPE_SEQUENCE,
+                span_to(chars),
+                format!("unknown escape sequence `\\{}`", char.escape_default()),
+            );
+            None
+        }
+    }
+}
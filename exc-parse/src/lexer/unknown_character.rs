@@ -0,0 +1,172 @@
+use crate::TokenKind;
+use exc_diagnostic::{error_codes, DiagnosticsSender};
+use exc_span::Span;
+
+/// Unicode characters that look like ASCII punctuation, along with their names.
+const LOOKALIKE_CHARACTERS: [(char, &str, char); 41] = [
+    ('\u{201C}', "Left Double Quotation Mark", '"'),
+    ('\u{201D}', "Right Double Quotation Mark", '"'),
+    ('\u{201E}', "Double Low-9 Quotation Mark", '"'),
+    ('\u{FF02}', "Fullwidth Quotation Mark", '"'),
+    ('\u{2018}', "Left Single Quotation Mark", '\''),
+    ('\u{2019}', "Right Single Quotation Mark", '\''),
+    ('\u{FF07}', "Fullwidth Apostrophe", '\''),
+    ('\u{037E}', "Greek Question Mark", ';'),
+    ('\u{FF1B}', "Fullwidth Semicolon", ';'),
+    ('\u{FF1A}', "Fullwidth Colon", ':'),
+    ('\u{FF0C}', "Fullwidth Comma", ','),
+    ('\u{3001}', "Ideographic Comma", ','),
+    ('\u{FF0E}', "Fullwidth Full Stop", '.'),
+    ('\u{3002}', "Ideographic Full Stop", '.'),
+    ('\u{FF08}', "Fullwidth Left Parenthesis", '('),
+    ('\u{FF09}', "Fullwidth Right Parenthesis", ')'),
+    ('\u{FF5B}', "Fullwidth Left Curly Bracket", '{'),
+    ('\u{FF5D}', "Fullwidth Right Curly Bracket", '}'),
+    ('\u{FF3B}', "Fullwidth Left Square Bracket", '['),
+    ('\u{FF3D}', "Fullwidth Right Square Bracket", ']'),
+    ('\u{FF1D}', "Fullwidth Equals Sign", '='),
+    ('\u{FF0B}', "Fullwidth Plus Sign", '+'),
+    ('\u{2212}', "Minus Sign", '-'),
+    ('\u{2013}', "En Dash", '-'),
+    ('\u{2014}', "Em Dash", '-'),
+    ('\u{FF0D}', "Fullwidth Hyphen-Minus", '-'),
+    ('\u{00D7}', "Multiplication Sign", '*'),
+    ('\u{2217}', "Asterisk Operator", '*'),
+    ('\u{FF0A}', "Fullwidth Asterisk", '*'),
+    ('\u{2215}', "Division Slash", '/'),
+    ('\u{FF0F}', "Fullwidth Solidus", '/'),
+    ('\u{FF01}', "Fullwidth Exclamation Mark", '!'),
+    ('\u{FF1C}', "Fullwidth Less-Than Sign", '<'),
+    ('\u{FF1E}', "Fullwidth Greater-Than Sign", '>'),
+    ('\u{FF5C}', "Fullwidth Vertical Line", '|'),
+    ('\u{FF06}', "Fullwidth Ampersand", '&'),
+    ('\u{FF05}', "Fullwidth Percent Sign", '%'),
+    ('\u{FF3E}', "Fullwidth Circumflex Accent", '^'),
+    ('\u{FF5E}', "Fullwidth Tilde", '~'),
+    ('\u{FF20}', "Fullwidth Commercial At", '@'),
+    ('\u{FF03}', "Fullwidth Number Sign", '#'),
+];
+
+/// Characters that are not visible at all, along with their names.
+const INVISIBLE_CHARACTERS: [(char, &str); 5] = [
+    ('\u{00AD}', "Soft Hyphen"),
+    ('\u{200B}', "Zero Width Space"),
+    ('\u{200D}', "Zero Width Joiner"),
+    ('\u{2060}', "Word Joiner"),
+    ('\u{FEFF}', "Zero Width No-Break Space"),
+];
+
+/// Returns `true` if the character is a look-alike of ASCII punctuation or an invisible character.
+pub fn is_replaceable_character(char: char) -> bool {
+    LOOKALIKE_CHARACTERS
+        .iter()
+        .any(|(lookalike, _, _)| *lookalike == char)
+        || INVISIBLE_CHARACTERS
+            .iter()
+            .any(|(invisible, _)| *invisible == char)
+}
+
+/// Reports a run of characters that cannot start any token.
+/// Returns the token kind to use instead, if the run is an obvious mistake that can be recovered from;
+/// a single look-alike of ASCII punctuation is replaced with the punctuation, and invisible characters with whitespace.
+pub fn report_unknown_characters(
+    content: &str,
+    span: Span,
+    diagnostics: &DiagnosticsSender,
+) -> Option<TokenKind> {
+    let mut sub_diagnostics = Vec::new();
+    let mut replacements = Vec::new();
+
+    for (offset, char) in content.char_indices() {
+        let low = span.low + offset as u32;
+        let char_span = Span::new(low, low + char.len_utf8() as u32);
+
+        if let Some((_, name, ascii)) = LOOKALIKE_CHARACTERS
+            .iter()
+            .find(|(lookalike, _, _)| *lookalike == char)
+        {
+            sub_diagnostics.push(diagnostics.sub_hint(
+                char_span,
+                format!(
+                    "Unicode character `{}` ({}) looks like `{}`, but it is not; consider replacing it with `{}`",
+                    char, name, ascii, ascii
+                ),
+            ));
+            replacements.push(punctuation_kind(*ascii));
+        } else if let Some((_, name)) = INVISIBLE_CHARACTERS
+            .iter()
+            .find(|(invisible, _)| *invisible == char)
+        {
+            sub_diagnostics.push(diagnostics.sub_hint(
+                char_span,
+                format!(
+                    "invisible character `U+{:04X}` ({}); consider removing it",
+                    char as u32, name
+                ),
+            ));
+            replacements.push(Some(TokenKind::Whitespace));
+        } else {
+            replacements.push(None);
+        }
+    }
+
+    let count = replacements.len();
+    diagnostics.error_sub(
+        error_codes::UNKNOWN_CHARACTER,
+        span,
+        format!(
+            "unknown {} `{}`",
+            if count == 1 {
+                "character"
+            } else {
+                "characters"
+            },
+            content.escape_debug()
+        ),
+        sub_diagnostics,
+    );
+
+    match replacements.as_slice() {
+        [replacement] => *replacement,
+        replacements
+            if replacements
+                .iter()
+                .all(|replacement| *replacement == Some(TokenKind::Whitespace)) =>
+        {
+            Some(TokenKind::Whitespace)
+        }
+        _ => None,
+    }
+}
+
+fn punctuation_kind(char: char) -> Option<TokenKind> {
+    let kind = match char {
+        '(' => TokenKind::OpenParen,
+        ')' => TokenKind::CloseParen,
+        '{' => TokenKind::OpenBrace,
+        '}' => TokenKind::CloseBrace,
+        '[' => TokenKind::OpenBracket,
+        ']' => TokenKind::CloseBracket,
+        '.' => TokenKind::Dot,
+        ',' => TokenKind::Comma,
+        ':' => TokenKind::Colon,
+        ';' => TokenKind::Semicolon,
+        '=' => TokenKind::Assign,
+        '!' => TokenKind::LogNot,
+        '<' => TokenKind::Lt,
+        '>' => TokenKind::Gt,
+        '+' => TokenKind::Add,
+        '-' => TokenKind::Sub,
+        '*' => TokenKind::Mul,
+        '/' => TokenKind::Div,
+        '%' => TokenKind::Mod,
+        '|' => TokenKind::BitOr,
+        '&' => TokenKind::BitAnd,
+        '^' => TokenKind::BitXor,
+        '~' => TokenKind::BitNot,
+        '@' => TokenKind::At,
+        // quotes and `#` start tokens that span more than a character; they cannot be replaced alone
+        _ => return None,
+    };
+    Some(kind)
+}
@@ -1,13 +1,17 @@
 mod ast;
 mod lexer;
+mod lossless;
 mod low_lexer;
 mod parse;
+mod reparse;
 mod token_skippers;
 
 pub use ast::*;
 pub use lexer::*;
+pub use lossless::*;
 pub use low_lexer::*;
 pub use parse::*;
+pub use reparse::*;
 pub use token_skippers::*;
 
 #[cfg(test)]
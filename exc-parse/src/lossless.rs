@@ -0,0 +1,133 @@
+use crate::{Token, TokenKind};
+use exc_span::{SourceFile, Span};
+
+/// A token along with the trivia (whitespace and comments) around it.
+/// The trailing trivia runs up to and including the end of the line the token is on;
+/// the trivia after that belongs to the next token as its leading trivia.
+#[derive(Debug, Clone, Hash)]
+pub struct LosslessToken {
+    pub leading_trivia: Vec<Token>,
+    pub token: Token,
+    pub trailing_trivia: Vec<Token>,
+}
+
+impl LosslessToken {
+    /// Returns the span of the token including its trivia.
+    pub fn full_span(&self) -> Span {
+        let low = self
+            .leading_trivia
+            .first()
+            .map_or(self.token.span.low, |trivia| trivia.span.low);
+        let high = self
+            .trailing_trivia
+            .last()
+            .map_or(self.token.span.high, |trivia| trivia.span.high);
+        Span::new(low, high)
+    }
+}
+
+/// All tokens of a source file along with the trivia between them, including the ones the parser drops.
+/// Since the lexer never skips a byte, it reproduces the source byte-for-byte, even if it has syntax errors.
+#[derive(Debug, Clone, Hash)]
+pub struct LosslessTokenStream {
+    pub tokens: Vec<LosslessToken>,
+    /// trivia after the last token, or the whole file if there are no tokens
+    pub eof_trivia: Vec<Token>,
+}
+
+impl LosslessTokenStream {
+    pub fn new(file: &SourceFile, token_stream: impl Iterator<Item = Token>) -> Self {
+        let mut tokens = Vec::<LosslessToken>::new();
+        let mut trivia = Vec::new();
+        let mut is_trailing = false;
+
+        for token in token_stream {
+            if !is_trivia(&token) {
+                tokens.push(LosslessToken {
+                    leading_trivia: std::mem::take(&mut trivia),
+                    token,
+                    trailing_trivia: Vec::new(),
+                });
+                is_trailing = true;
+                continue;
+            }
+
+            let last = match tokens.last_mut() {
+                Some(last) if is_trailing => last,
+                _ => {
+                    trivia.push(token);
+                    continue;
+                }
+            };
+
+            let newline = match file.slice(token.span).find('\n') {
+                Some(newline) => newline as u32,
+                None => {
+                    last.trailing_trivia.push(token);
+                    continue;
+                }
+            };
+
+            is_trailing = false;
+
+            match token.kind {
+                // split the whitespace at the end of the line
+                TokenKind::Whitespace if token.span.low + newline + 1 < token.span.high => {
+                    let end_of_line = token.span.low + newline + 1;
+                    last.trailing_trivia.push(Token::new(
+                        Span::new(token.span.low, end_of_line),
+                        token.kind,
+                    ));
+                    trivia.push(Token::new(
+                        Span::new(end_of_line, token.span.high),
+                        token.kind,
+                    ));
+                }
+                // comments are never split, e.g. a block comment spanning multiple lines
+                _ => {
+                    last.trailing_trivia.push(token);
+                }
+            }
+        }
+
+        Self {
+            tokens,
+            eof_trivia: trivia,
+        }
+    }
+
+    /// Returns the token that begins at the beginning of the given span, e.g. a token stored in the AST.
+    pub fn find(&self, span: Span) -> Option<&LosslessToken> {
+        self.tokens
+            .binary_search_by_key(&span.low, |token| token.token.span.low)
+            .ok()
+            .map(|index| &self.tokens[index])
+    }
+
+    /// Reproduces the source from the tokens and the trivia.
+    pub fn to_source(&self, file: &SourceFile) -> String {
+        let mut source = String::with_capacity(file.content().len());
+
+        for token in &self.tokens {
+            for trivia in &token.leading_trivia {
+                source.push_str(file.slice(trivia.span));
+            }
+
+            source.push_str(file.slice(token.token.span));
+
+            for trivia in &token.trailing_trivia {
+                source.push_str(file.slice(trivia.span));
+            }
+        }
+
+        for trivia in &self.eof_trivia {
+            source.push_str(file.slice(trivia.span));
+        }
+
+        source
+    }
+}
+
+fn is_trivia(token: &Token) -> bool {
+    matches!(token.kind, TokenKind::Whitespace | TokenKind::Comment)
+}
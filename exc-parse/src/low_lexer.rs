@@ -1,11 +1,13 @@
 mod cursor;
 mod low_token;
+mod low_token_interpolated_string_part;
 mod low_token_kind;
 mod low_token_literal_kind;
 mod low_token_number_literal_kind;
 
 pub use cursor::*;
 pub use low_token::*;
+pub use low_token_interpolated_string_part::*;
 pub use low_token_kind::*;
 pub use low_token_literal_kind::*;
 pub use low_token_number_literal_kind::*;
@@ -14,24 +16,188 @@ use std::iter::from_fn as iter_from_fn;
 use unicode_xid::UnicodeXID;
 
 pub fn low_token_iter(mut input: &str) -> impl Iterator<Item = LowToken> + '_ {
+    let mut state = LowLexerState::default();
+
     iter_from_fn(move || {
         if input.is_empty() {
             return None;
         }
 
-        let token = next(input);
+        let mut token = state.next(input);
+
+        // Interpolations do not span lines, same as strings; a part that opens an interpolation
+        // not closed on the same line is cut off at the end of the line as an unterminated part.
+        if let LowTokenKind::InterpolatedString {
+            part:
+                part @ (LowTokenInterpolatedStringPart::Head | LowTokenInterpolatedStringPart::Middle),
+            ..
+        } = token.kind
+        {
+            if !is_interpolation_closed(&input[token.len as usize..]) {
+                token = cut_off_interpolated_string_part(input, part);
+            }
+        }
+
+        state.update(input, token);
         input = &input[token.len as usize..];
         Some(token)
     })
 }
 
-fn next(input: impl AsRef<str>) -> LowToken {
+#[derive(Default)]
+struct LowLexerState {
+    /// Brace depths of the interpolations being lexed, innermost last;
+    /// the `}` at depth 0 closes the interpolation and continues the string.
+    interpolations: Vec<u32>,
+    /// Whether the last token other than whitespace and comments is `break` or `continue`,
+    /// which are followed by a label e.g. `break 'outer`.
+    after_jump: bool,
+}
+
+impl LowLexerState {
+    fn next(&self, input: &str) -> LowToken {
+        match self.interpolations.last() {
+            Some(0) if input.starts_with('}') => next_interpolated_string_continuation(input),
+            _ => next(input, self.after_jump),
+        }
+    }
+
+    /// Updates the state with the token that is lexed at the start of `input`.
+    fn update(&mut self, input: &str, token: LowToken) {
+        match token.kind {
+            LowTokenKind::OpenBrace => {
+                if let Some(depth) = self.interpolations.last_mut() {
+                    *depth += 1;
+                }
+            }
+            LowTokenKind::CloseBrace => {
+                if let Some(depth) = self.interpolations.last_mut() {
+                    *depth -= 1;
+                }
+            }
+            LowTokenKind::InterpolatedString { part, .. } => {
+                // the continuation closes the interpolation, and may open another one
+                if let LowTokenInterpolatedStringPart::Middle
+                | LowTokenInterpolatedStringPart::Tail = part
+                {
+                    self.interpolations.pop();
+                }
+
+                if let LowTokenInterpolatedStringPart::Head
+                | LowTokenInterpolatedStringPart::Middle = part
+                {
+                    self.interpolations.push(0);
+                }
+            }
+            _ => {}
+        }
+
+        match token.kind {
+            LowTokenKind::Whitespace
+            | LowTokenKind::Comment
+            | LowTokenKind::BlockComment { .. } => {}
+            LowTokenKind::Id => {
+                self.after_jump = matches!(&input[..token.len as usize], "break" | "continue");
+            }
+            _ => {
+                self.after_jump = false;
+            }
+        }
+    }
+}
+
+/// Checks that the interpolation opened right before `input` is closed before the end of the line.
+fn is_interpolation_closed(mut input: &str) -> bool {
+    let mut state = LowLexerState {
+        interpolations: vec![0],
+        after_jump: false,
+    };
+
+    while !is_at_line_end(input) {
+        let token = state.next(input);
+
+        // a continuation of the outermost string closes the interpolation, even if it opens another one
+        if let (
+            1,
+            LowTokenKind::InterpolatedString {
+                part: LowTokenInterpolatedStringPart::Middle | LowTokenInterpolatedStringPart::Tail,
+                ..
+            },
+        ) = (state.interpolations.len(), token.kind)
+        {
+            return true;
+        }
+
+        state.update(input, token);
+        input = &input[token.len as usize..];
+    }
+
+    false
+}
+
+fn is_at_line_end(input: &str) -> bool {
+    input
+        .trim_start_matches(|char: char| char.is_whitespace() && char != '\n')
+        .chars()
+        .next()
+        .map_or(true, |char| char == '\n')
+}
+
+/// Lexes the rest of the line as an unterminated part, in place of the `part` whose interpolation is never closed.
+fn cut_off_interpolated_string_part(input: &str, part: LowTokenInterpolatedStringPart) -> LowToken {
+    let part = match part {
+        LowTokenInterpolatedStringPart::Head => LowTokenInterpolatedStringPart::Whole,
+        _ => LowTokenInterpolatedStringPart::Tail,
+    };
+    let len = input.find('\n').unwrap_or(input.len());
+    LowToken::new(
+        LowTokenKind::InterpolatedString {
+            part,
+            terminated: false,
+        },
+        len as u32,
+    )
+}
+
+/// Returns the offset of the `{` that opens an interpolation in the body of an interpolated string part, if any.
+pub fn find_interpolation_opening(body: &str) -> Option<u32> {
+    let mut cursor = Cursor::new(body);
+
+    match consume_interpolated_string_part(&mut cursor) {
+        (true, _) => Some(cursor.len_consumed() - 1),
+        (false, _) => None,
+    }
+}
+
+fn next(input: impl AsRef<str>, after_jump: bool) -> LowToken {
     let mut cursor = Cursor::new(input.as_ref());
     let kind = match cursor.consume().unwrap() {
         char if char.is_whitespace() => {
             consume_while(&mut cursor, |char| char.is_whitespace());
             LowTokenKind::Whitespace
         }
+        'f' if cursor.first() == '"' => {
+            cursor.consume();
+            let (opens_interpolation, terminated) = consume_interpolated_string_part(&mut cursor);
+            LowTokenKind::InterpolatedString {
+                part: if opens_interpolation {
+                    LowTokenInterpolatedStringPart::Head
+                } else {
+                    LowTokenInterpolatedStringPart::Whole
+                },
+                terminated,
+            }
+        }
+        'r' if is_raw_string_start(&cursor) => {
+            let hashes = consume_while_count(&mut cursor, |char| char == '#');
+            cursor.consume();
+            LowTokenKind::Literal {
+                kind: LowTokenLiteralKind::RawString {
+                    hashes,
+                    terminated: consume_literal_raw_string(&mut cursor, hashes),
+                },
+            }
+        }
         char if is_id_start(char) => {
             consume_while(&mut cursor, |char| is_id_continue(char));
             LowTokenKind::Id
@@ -49,10 +215,23 @@ fn next(input: impl AsRef<str>) -> LowToken {
                 kind: LowTokenLiteralKind::Number { kind, suffix_start },
             }
         }
-        '#' => {
-            consume_while(&mut cursor, |char| char != '\n');
-            LowTokenKind::Comment
-        }
+        '#' => match cursor.first() {
+            '[' => {
+                cursor.consume();
+                LowTokenKind::BlockComment {
+                    terminated: consume_block_comment(&mut cursor),
+                }
+            }
+            // "###" and more are ordinary comments, e.g. for separator lines
+            '#' if cursor.second() != '#' => {
+                consume_while(&mut cursor, |char| char != '\n');
+                LowTokenKind::DocComment
+            }
+            _ => {
+                consume_while(&mut cursor, |char| char != '\n');
+                LowTokenKind::Comment
+            }
+        },
         '(' => LowTokenKind::OpenParen,
         ')' => LowTokenKind::CloseParen,
         '{' => LowTokenKind::OpenBrace,
@@ -76,11 +255,25 @@ fn next(input: impl AsRef<str>) -> LowToken {
         '&' => LowTokenKind::And,
         '^' => LowTokenKind::Caret,
         '~' => LowTokenKind::Tilde,
+        '@' => LowTokenKind::At,
+        '\'' if is_label(&cursor, after_jump) => {
+            consume_while(&mut cursor, |char| is_id_continue(char));
+            LowTokenKind::Label
+        }
         '\'' => LowTokenKind::Literal {
             kind: LowTokenLiteralKind::Character {
                 terminated: consume_literal_character(&mut cursor),
             },
         },
+        '"' if cursor.first() == '"' && cursor.second() == '"' => {
+            cursor.consume();
+            cursor.consume();
+            LowTokenKind::Literal {
+                kind: LowTokenLiteralKind::MultiLineString {
+                    terminated: consume_literal_multi_line_string(&mut cursor),
+                },
+            }
+        }
         '"' => LowTokenKind::Literal {
             kind: LowTokenLiteralKind::String {
                 terminated: consume_literal_string(&mut cursor),
@@ -91,12 +284,58 @@ fn next(input: impl AsRef<str>) -> LowToken {
     LowToken::new(kind, cursor.len_consumed())
 }
 
+fn next_interpolated_string_continuation(input: &str) -> LowToken {
+    let mut cursor = Cursor::new(input);
+    cursor.consume(); // `}`
+
+    let (opens_interpolation, terminated) = consume_interpolated_string_part(&mut cursor);
+    let kind = LowTokenKind::InterpolatedString {
+        part: if opens_interpolation {
+            LowTokenInterpolatedStringPart::Middle
+        } else {
+            LowTokenInterpolatedStringPart::Tail
+        },
+        terminated,
+    };
+    LowToken::new(kind, cursor.len_consumed())
+}
+
 fn consume_while(cursor: &mut Cursor, mut pred: impl FnMut(char) -> bool) {
     while cursor.is_exists() && pred(cursor.first()) {
         cursor.consume();
     }
 }
 
+fn consume_while_count(cursor: &mut Cursor, mut pred: impl FnMut(char) -> bool) -> u32 {
+    let mut count = 0;
+
+    while cursor.is_exists() && pred(cursor.first()) {
+        cursor.consume();
+        count += 1;
+    }
+
+    count
+}
+
+/// Checks that the `'` just consumed begins a label rather than a character literal;
+/// labels are defined with a `:` e.g. `'outer: loop`, or come after `break` and `continue`.
+fn is_label(cursor: &Cursor, after_jump: bool) -> bool {
+    if !is_id_start(cursor.first()) {
+        return false;
+    }
+
+    let mut cursor = cursor.clone();
+    consume_while(&mut cursor, |char| is_id_continue(char));
+
+    // closed like a character literal e.g. 'a' or 'ab'
+    if cursor.first() == '\'' {
+        return false;
+    }
+
+    consume_while(&mut cursor, |char| char.is_whitespace() && char != '\n');
+    after_jump || cursor.first() == ':'
+}
+
 fn is_id_start(char: char) -> bool {
     ('a'..='z').contains(&char)
         || ('A'..='Z').contains(&char)
@@ -112,27 +351,56 @@ fn is_id_continue(char: char) -> bool {
         || (char > '\x7f' && char.is_xid_continue())
 }
 
+fn consume_block_comment(cursor: &mut Cursor) -> bool {
+    let mut depth = 1;
+
+    while cursor.is_exists() {
+        match (cursor.first(), cursor.second()) {
+            ('#', '[') => {
+                cursor.consume();
+                cursor.consume();
+                depth += 1;
+            }
+            (']', '#') => {
+                cursor.consume();
+                cursor.consume();
+                depth -= 1;
+
+                if depth == 0 {
+                    return true;
+                }
+            }
+            _ => {
+                cursor.consume();
+            }
+        }
+    }
+
+    false
+}
+
 fn consume_literal_number(cursor: &mut Cursor, first_char: char) -> LowTokenNumberLiteralKind {
+    // Digits that are invalid for the base (e.g. `2` in `0b102`) are consumed too; they are reported later.
     let kind = if first_char == '0' {
         match cursor.first() {
-            'b' if cursor.second().is_digit(2) => {
+            'b' if is_digit_or_separator(cursor.second()) => {
                 cursor.consume();
-                consume_while(cursor, |char| char.is_digit(2));
+                consume_while(cursor, is_digit_or_separator);
                 LowTokenNumberLiteralKind::IntegerBinary
             }
-            'o' if cursor.second().is_digit(8) => {
+            'o' if is_digit_or_separator(cursor.second()) => {
                 cursor.consume();
-                consume_while(cursor, |char| char.is_digit(8));
+                consume_while(cursor, is_digit_or_separator);
                 LowTokenNumberLiteralKind::IntegerOctal
             }
-            'x' if cursor.second().is_digit(16) => {
+            'x' if is_hex_digit_or_separator(cursor.second()) => {
                 cursor.consume();
-                consume_while(cursor, |char| char.is_digit(16));
+                consume_while(cursor, is_hex_digit_or_separator);
                 LowTokenNumberLiteralKind::IntegerHexadecimal
             }
-            '0'..='9' => {
+            '0'..='9' | '_' => {
                 cursor.consume();
-                consume_while(cursor, |char| char.is_digit(10));
+                consume_while(cursor, is_digit_or_separator);
                 LowTokenNumberLiteralKind::IntegerDecimal
             }
             '.' | 'e' | 'E' => LowTokenNumberLiteralKind::IntegerDecimal,
@@ -146,52 +414,53 @@ fn consume_literal_number(cursor: &mut Cursor, first_char: char) -> LowTokenNumb
         return kind;
     }
 
+    consume_while(cursor, is_digit_or_separator);
+
     match cursor.first() {
-        '.' if cursor.second().is_digit(10) => {
+        '.' if cursor.second().is_ascii_digit() => {
             cursor.consume();
-            consume_while(cursor, |char| char.is_digit(10));
+            consume_while(cursor, is_digit_or_separator);
 
-            match (cursor.first(), cursor.second(), cursor.lookup(2)) {
-                ('e' | 'E', '+' | '-', digit) if digit.is_digit(10) => {
-                    cursor.consume();
-                    cursor.consume();
-                    consume_while(cursor, |char| char.is_digit(10));
-                }
-                ('e' | 'E', digit, _) if digit.is_digit(10) => {
-                    cursor.consume();
-                    consume_while(cursor, |char| char.is_digit(10));
-                }
-                _ => {}
+            if is_exponent_start(cursor) {
+                consume_exponent(cursor);
             }
 
             LowTokenNumberLiteralKind::Float
         }
-        'e' | 'E'
-            if match cursor.second() {
-                '+' | '-' if cursor.lookup(2).is_digit(10) => true,
-                digit if digit.is_digit(10) => true,
-                _ => false,
-            } =>
-        {
-            cursor.consume();
-
-            match cursor.first() {
-                '+' | '-' => {
-                    cursor.consume();
-                }
-                _ => {}
-            }
-
-            consume_while(cursor, |char| char.is_digit(10));
+        'e' | 'E' if is_exponent_start(cursor) => {
+            consume_exponent(cursor);
             LowTokenNumberLiteralKind::Float
         }
-        _ => {
-            consume_while(cursor, |char| char.is_digit(10));
-            LowTokenNumberLiteralKind::IntegerDecimal
-        }
+        _ => LowTokenNumberLiteralKind::IntegerDecimal,
+    }
+}
+
+fn is_digit_or_separator(char: char) -> bool {
+    char.is_ascii_digit() || char == '_'
+}
+
+fn is_hex_digit_or_separator(char: char) -> bool {
+    char.is_ascii_hexdigit() || char == '_'
+}
+
+fn is_exponent_start(cursor: &Cursor) -> bool {
+    match (cursor.first(), cursor.second(), cursor.lookup(2)) {
+        ('e' | 'E', '+' | '-', digit) => digit.is_ascii_digit(),
+        ('e' | 'E', digit, _) => digit.is_ascii_digit(),
+        _ => false,
     }
 }
 
+fn consume_exponent(cursor: &mut Cursor) {
+    cursor.consume();
+
+    if let '+' | '-' = cursor.first() {
+        cursor.consume();
+    }
+
+    consume_while(cursor, is_digit_or_separator);
+}
+
 fn consume_literal_character(cursor: &mut Cursor) -> bool {
     // Normal case e.g. 'a'
     if cursor.first() != '\\' && cursor.second() == '\'' {
@@ -200,39 +469,88 @@ fn consume_literal_character(cursor: &mut Cursor) -> bool {
         return true;
     }
 
-    match cursor.first() {
-        '\'' => {
-            cursor.consume();
-            return true;
-        }
-        '\\' => {
-            cursor.consume();
-            cursor.consume();
-
-            if cursor.first() == '\'' {
+    // Escape sequences or malformed literals e.g. '\u{1F600}', 'ab'; stops at the end of the line.
+    while cursor.is_exists() {
+        match cursor.first() {
+            '\'' => {
                 cursor.consume();
                 return true;
             }
+            '\n' => return false,
+            '\\' => {
+                cursor.consume();
+                cursor.consume();
+            }
+            _ => {
+                cursor.consume();
+            }
+        }
+    }
+
+    false
+}
+
+fn consume_literal_string(cursor: &mut Cursor) -> bool {
+    let start = cursor.clone();
 
-            return false;
+    while let Some(char) = cursor.consume() {
+        match char {
+            '"' => return true,
+            '\\' => {
+                cursor.consume();
+            }
+            _ => {}
         }
-        _ => {
-            cursor.consume();
+    }
 
-            if cursor.first() == '\'' {
+    // Unterminated; cut the literal at the end of the line, so that the rest of the file can be lexed.
+    *cursor = start;
+    consume_while(cursor, |char| char != '\n');
+
+    false
+}
+
+fn is_raw_string_start(cursor: &Cursor) -> bool {
+    // The leading `r` is already consumed; e.g. r"..." or r##"..."##
+    let mut offset = 0;
+
+    while cursor.lookup(offset) == '#' {
+        offset += 1;
+    }
+
+    cursor.lookup(offset) == '"'
+}
+
+fn consume_literal_raw_string(cursor: &mut Cursor, hashes: u32) -> bool {
+    let start = cursor.clone();
+
+    while let Some(char) = cursor.consume() {
+        if char == '"' && (0..hashes).all(|offset| cursor.lookup(offset) == '#') {
+            for _ in 0..hashes {
                 cursor.consume();
-                return true;
             }
 
-            return false;
+            return true;
         }
     }
+
+    // Unterminated; same as ordinary string literals.
+    *cursor = start;
+    consume_while(cursor, |char| char != '\n');
+
+    false
 }
 
-fn consume_literal_string(cursor: &mut Cursor) -> bool {
+fn consume_literal_multi_line_string(cursor: &mut Cursor) -> bool {
+    // Unlike the other string literals, unterminated multi-line strings run to the end of the file;
+    // their content is expected to span several lines, so there is no better place to cut them.
     while let Some(char) = cursor.consume() {
         match char {
-            '"' => return true,
+            '"' if cursor.first() == '"' && cursor.second() == '"' => {
+                cursor.consume();
+                cursor.consume();
+                return true;
+            }
             '\\' => {
                 cursor.consume();
             }
@@ -242,3 +560,33 @@ fn consume_literal_string(cursor: &mut Cursor) -> bool {
 
     false
 }
+
+/// Consumes a part of an interpolated string, up to the closing `"` or the `{` opening an interpolation.
+/// Returns whether an interpolation is opened, and whether the part is terminated.
+fn consume_interpolated_string_part(cursor: &mut Cursor) -> (bool, bool) {
+    let start = cursor.clone();
+
+    while let Some(char) = cursor.consume() {
+        match char {
+            '"' => return (false, true),
+            // `{{` is an escaped brace
+            '{' if cursor.first() == '{' => {
+                cursor.consume();
+            }
+            '{' => return (true, true),
+            // `\u{...}` is consumed as a whole, so its `{` does not open an interpolation
+            '\\' => {
+                if cursor.consume() == Some('u') && cursor.first() == '{' {
+                    consume_while(cursor, |char| !matches!(char, '}' | '"' | '\n'));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Unterminated; same as ordinary string literals.
+    *cursor = start;
+    consume_while(cursor, |char| char != '\n');
+
+    (false, false)
+}
@@ -1,6 +1,6 @@
 use std::str::Chars;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Cursor<'s> {
     chars: Chars<'s>,
     initial_length: u32,
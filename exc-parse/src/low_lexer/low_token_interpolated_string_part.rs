@@ -0,0 +1,7 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LowTokenInterpolatedStringPart {
+    Whole,  // f"..." without interpolations
+    Head,   // f"...{
+    Middle, // }...{
+    Tail,   // }..."
+}
@@ -1,33 +1,45 @@
-use crate::LowTokenLiteralKind;
+use crate::{LowTokenInterpolatedStringPart, LowTokenLiteralKind};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum LowTokenKind {
     Unknown,
     Whitespace,
-    Comment,      // "#"
-    OpenParen,    // "("
-    CloseParen,   // ")"
-    OpenBrace,    // "{"
-    CloseBrace,   // "}"
-    OpenBracket,  // "["
+    Comment, // "#"
+    BlockComment {
+        terminated: bool,
+    }, // "#[ ... ]#", may be nested
+    DocComment, // "##"
+    OpenParen, // "("
+    CloseParen, // ")"
+    OpenBrace, // "{"
+    CloseBrace, // "}"
+    OpenBracket, // "["
     CloseBracket, // "]"
-    Dot,          // "."
-    Comma,        // ","
-    Colon,        // ":"
-    Semicolon,    // ";"
-    Eq,           // "="
-    Bang,         // "!"
-    Lt,           // "<"
-    Gt,           // ">"
-    Plus,         // "+"
-    Minus,        // "-"
-    Star,         // "*"
-    Slash,        // "/"
-    Percent,      // "%"
-    Or,           // "|"
-    And,          // "&"
-    Caret,        // "^"
-    Tilde,        // "~"
-    Id,           // identifier or keyword
-    Literal { kind: LowTokenLiteralKind },
+    Dot,     // "."
+    Comma,   // ","
+    Colon,   // ":"
+    Semicolon, // ";"
+    Eq,      // "="
+    Bang,    // "!"
+    Lt,      // "<"
+    Gt,      // ">"
+    Plus,    // "+"
+    Minus,   // "-"
+    Star,    // "*"
+    Slash,   // "/"
+    Percent, // "%"
+    Or,      // "|"
+    And,     // "&"
+    Caret,   // "^"
+    Tilde,   // "~"
+    At,      // "@"
+    Id,      // identifier or keyword
+    Label,   // "'" followed by an identifier e.g. 'outer
+    Literal {
+        kind: LowTokenLiteralKind,
+    },
+    InterpolatedString {
+        part: LowTokenInterpolatedStringPart,
+        terminated: bool,
+    },
 }
@@ -12,4 +12,11 @@ pub enum LowTokenLiteralKind {
     String {
         terminated: bool,
     },
+    RawString {
+        hashes: u32,
+        terminated: bool,
+    },
+    MultiLineString {
+        terminated: bool,
+    },
 }
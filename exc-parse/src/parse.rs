@@ -5,35 +5,52 @@ pub use parser::*;
 pub use token_type::*;
 
 use crate::{
-    before_expr, before_expr_call_item, before_expr_struct_literal_field_item,
-    before_extern_block_item, before_fn_params_item, before_generic_arg_item,
-    before_generic_param_item, before_generic_where_item, before_generic_where_item_condition_item,
-    before_impl_block_item, before_interface_item, before_interface_item_fn_decl_params_item,
-    before_module_item, before_prototype_params_item, before_stmt, before_struct_fields_item,
-    before_ty_fn_pointer_param_item, before_use_path_item_group_item, ASTAliasDef, ASTExpr,
-    ASTExprAs, ASTExprBinary, ASTExprBinaryOperator, ASTExprBinaryOperatorKind, ASTExprCall,
-    ASTExprCallCallee, ASTExprKind, ASTExprLiteral, ASTExprMember, ASTExprParen, ASTExprPath,
-    ASTExprStructLiteral, ASTExprStructLiteralField, ASTExprUnary, ASTExprUnaryOperator,
-    ASTExprUnaryOperatorKind, ASTExternBlock, ASTExternBlockItem, ASTExternBlockItemKind, ASTFnDef,
-    ASTFnParam, ASTFnResult, ASTGenericArg, ASTGenericParam, ASTGenericParamItem, ASTGenericWhere,
-    ASTGenericWhereItem, ASTGenericWhereItemCondition, ASTGenericWhereItemConditionItem,
-    ASTImplBlock, ASTImplBlockInterface, ASTImplBlockItem, ASTImplBlockItemKind, ASTInterfaceDef,
-    ASTInterfaceDefItem, ASTInterfaceDefItemFnDecl, ASTInterfaceDefItemKind, ASTModule,
-    ASTModuleDecl, ASTModuleDef, ASTModuleItem, ASTModuleItemKind, ASTPath, ASTPathSegment,
-    ASTPrototypeDef, ASTStmt, ASTStmtAssignment, ASTStmtAssignmentOperator,
-    ASTStmtAssignmentOperatorKind, ASTStmtBlock, ASTStmtBreak, ASTStmtContinue, ASTStmtExpr,
-    ASTStmtIf, ASTStmtIfElse, ASTStmtIfElseIf, ASTStmtKind, ASTStmtLet, ASTStmtLetExpr,
-    ASTStmtLetTy, ASTStmtLoop, ASTStmtReturn, ASTStmtWhile, ASTStructDef, ASTStructDefField, ASTTy,
-    ASTTyArray, ASTTyFnPointer, ASTTyKind, ASTTyParen, ASTTySpan, ASTUse, ASTUsePath,
+    before_attribute_args_item, before_enum_variant_tuple_item, before_enum_variants_item,
+    before_expr, before_expr_array_literal_item, before_expr_call_item, before_expr_match_arm_item,
+    before_expr_struct_literal_field_item, before_expr_tuple_item, before_extern_block_item,
+    before_fn_params_item, before_generic_arg_item, before_generic_param_item,
+    before_generic_where_item, before_generic_where_item_condition_item, before_impl_block_item,
+    before_interface_item, before_interface_item_fn_decl_params_item, before_module_item,
+    before_pattern_array_item, before_pattern_struct_field_item, before_pattern_tuple_item,
+    before_prototype_params_item, before_stmt, before_struct_fields_item,
+    before_ty_fn_pointer_param_item, before_ty_tuple_item, before_use_path_item_group_item,
+    ASTAliasDef, ASTAttribute, ASTAttributeArgs, ASTConstDef, ASTDocComment, ASTEnumDef,
+    ASTEnumDefVariant, ASTEnumDefVariantKind, ASTEnumDefVariantStruct, ASTEnumDefVariantTuple,
+    ASTExpr, ASTExprArrayLiteral, ASTExprArrayRepeat, ASTExprAs, ASTExprBinary,
+    ASTExprBinaryOperator, ASTExprBinaryOperatorKind, ASTExprCall, ASTExprCallCallee,
+    ASTExprClosure, ASTExprIf, ASTExprIfElse, ASTExprIfElseIf, ASTExprIndex,
+    ASTExprInterpolatedString, ASTExprInterpolatedStringInterpolation, ASTExprKind, ASTExprLiteral,
+    ASTExprLoop, ASTExprMatch, ASTExprMatchArm, ASTExprMatchArmBody, ASTExprMember,
+    ASTExprMemberKind, ASTExprMemberPositional, ASTExprParen, ASTExprPath, ASTExprRange,
+    ASTExprRangeOperator, ASTExprRangeOperatorKind, ASTExprStructLiteral,
+    ASTExprStructLiteralField, ASTExprTuple, ASTExprUnary, ASTExprUnaryOperator,
+    ASTExprUnaryOperatorKind, ASTExternBlock, ASTExternBlockAbi, ASTExternBlockItem,
+    ASTExternBlockItemKind, ASTFnDef, ASTFnParam, ASTFnResult, ASTGenericArg, ASTGenericParam,
+    ASTGenericParamItem, ASTGenericWhere, ASTGenericWhereItem, ASTGenericWhereItemCondition,
+    ASTGenericWhereItemConditionItem, ASTImplBlock, ASTImplBlockInterface, ASTImplBlockItem,
+    ASTImplBlockItemKind, ASTInterfaceDef, ASTInterfaceDefItem, ASTInterfaceDefItemFnDecl,
+    ASTInterfaceDefItemKind, ASTLoopLabel, ASTModule, ASTModuleDecl, ASTModuleDef, ASTModuleItem,
+    ASTModuleItemKind, ASTPath, ASTPathSegment, ASTPattern, ASTPatternArray, ASTPatternBinding,
+    ASTPatternKind, ASTPatternLiteral, ASTPatternPath, ASTPatternRange, ASTPatternStruct,
+    ASTPatternStructField, ASTPatternStructFieldPattern, ASTPatternTuple, ASTPatternTupleStruct,
+    ASTPatternWildcard, ASTPrototypeDef, ASTStaticDef, ASTStmt, ASTStmtAssignment,
+    ASTStmtAssignmentOperator, ASTStmtAssignmentOperatorKind, ASTStmtBlock, ASTStmtBreak,
+    ASTStmtContinue, ASTStmtExpr, ASTStmtFor, ASTStmtKind, ASTStmtLet, ASTStmtLetExpr,
+    ASTStmtLetTy, ASTStmtReturn, ASTStmtWhile, ASTStructDef, ASTStructDefField, ASTTy, ASTTyArray,
+    ASTTyFnPointer, ASTTyKind, ASTTyParen, ASTTySpan, ASTTyTuple, ASTUse, ASTUsePath,
     ASTUsePathItem, ASTUsePathItemGroup, ASTUsePathItemKind, ASTUsePathItemSingle,
     ASTUsePathItemSingleAlias, ASTUsePathPrefix, ASTUsePathPrefixSegment,
-    ASTUsePathPrefixSegmentKind, NodeIdAllocator, Punctuated, PunctuatedItem, Token, TokenKind,
-    KEYWORD_ALIAS, KEYWORD_AS, KEYWORD_BREAK, KEYWORD_CONTINUE, KEYWORD_ELSE, KEYWORD_EXTERN,
-    KEYWORD_FN, KEYWORD_IF, KEYWORD_IMPL, KEYWORD_INTERFACE, KEYWORD_LET, KEYWORD_LOOP,
-    KEYWORD_MODULE, KEYWORD_PROTOTYPE, KEYWORD_PUB, KEYWORD_RETURN, KEYWORD_SELF, KEYWORD_STRUCT,
-    KEYWORD_SUPER, KEYWORD_USE, KEYWORD_WHERE, KEYWORD_WHILE,
+    ASTUsePathPrefixSegmentKind, ASTVisibility, ASTVisibilityScope, ASTVisibilityScopeIn,
+    ASTVisibilityScopeKind, NodeIdAllocator, Punctuated, PunctuatedItem, Token, TokenKind,
+    TokenLiteralKind, TokenLiteralValue, KEYWORD_ALIAS, KEYWORD_AS, KEYWORD_BREAK, KEYWORD_CONST,
+    KEYWORD_CONTINUE, KEYWORD_ELSE, KEYWORD_ENUM, KEYWORD_EXTERN, KEYWORD_FN, KEYWORD_FOR,
+    KEYWORD_IF, KEYWORD_IMPL, KEYWORD_IN, KEYWORD_INTERFACE, KEYWORD_LET, KEYWORD_LOOP,
+    KEYWORD_MATCH, KEYWORD_MODULE, KEYWORD_PROTOTYPE, KEYWORD_PUB, KEYWORD_RETURN, KEYWORD_SELF,
+    KEYWORD_STATIC, KEYWORD_STRUCT, KEYWORD_SUPER, KEYWORD_UNDERSCORE, KEYWORD_USE, KEYWORD_WHERE,
+    KEYWORD_WHILE,
 };
 use exc_diagnostic::DiagnosticsSender;
+use exc_symbol::Symbol;
 
 pub fn parse_module(
     token_stream: impl Iterator<Item = Token>,
@@ -52,6 +69,8 @@ where
         let mut items = Vec::new();
 
         while self.is_exists() {
+            let pos = self.current_pos();
+
             match self.parse_module_item() {
                 Ok(item) => {
                     items.push(item);
@@ -59,6 +78,13 @@ where
                 Err(_) => {
                     // eat erroneous tokens
                     self.skip_tokens(|token| before_module_item(token));
+                    // there's no enclosing block at the top level; eat stray closing braces too
+                    self.kind(TokenKind::CloseBrace);
+
+                    // eat at least one token, e.g. a stray `pub`, so that the loop makes progress
+                    if self.current_pos() == pos {
+                        self.skip_token();
+                    }
                 }
             }
         }
@@ -72,32 +98,33 @@ where
 
     pub fn parse_module_item(&mut self) -> Result<ASTModuleItem, ()> {
         let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        let attributes = self.parse_attributes()?;
+        let offset = self.lookup_visibility_len(0);
 
-        let kind = if self.lookup_keyword(0, *KEYWORD_USE)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_USE))
-        {
+        let kind = if self.lookup_keyword(offset, *KEYWORD_USE) {
             ASTModuleItemKind::Use(self.parse_use()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_ALIAS)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_ALIAS))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_ALIAS) {
             ASTModuleItemKind::AliasDef(self.parse_alias_def()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_MODULE)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_MODULE))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_CONST) {
+            ASTModuleItemKind::ConstDef(self.parse_const_def()?.into())
+        } else if self.lookup_keyword(offset, *KEYWORD_STATIC) {
+            ASTModuleItemKind::StaticDef(self.parse_static_def()?.into())
+        } else if self.lookup_keyword(offset, *KEYWORD_MODULE) {
             self.parse_module_decl_or_def()?
         } else if self.lookup_keyword(0, *KEYWORD_EXTERN) {
             ASTModuleItemKind::ExternBlock(self.parse_extern_block()?)
-        } else if self.lookup_keyword(0, *KEYWORD_FN)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_FN))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_FN) {
             ASTModuleItemKind::FnDef(self.parse_fn_def()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_STRUCT)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_STRUCT))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_STRUCT) {
             ASTModuleItemKind::StructDef(self.parse_struct_def()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_INTERFACE)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_INTERFACE))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_ENUM) {
+            ASTModuleItemKind::EnumDef(self.parse_enum_def()?.into())
+        } else if self.lookup_keyword(offset, *KEYWORD_INTERFACE) {
             ASTModuleItemKind::InterfaceDef(self.parse_interface_def()?.into())
         } else {
             ASTModuleItemKind::ImplBlock(self.parse_impl_block()?)
@@ -106,13 +133,285 @@ where
         Ok(ASTModuleItem {
             id,
             span: self.make_span(pos),
+            doc_comment,
+            attributes,
+            kind,
+        })
+    }
+
+    /// Returns `true` if a module item other than the allowed ones begins at the current position,
+    /// e.g. `const` in an impl block.
+    pub fn lookup_disallowed_module_item(&mut self, allowed: &[Symbol]) -> bool {
+        let offset = self.lookup_visibility_len(0);
+        let keywords = [
+            *KEYWORD_USE,
+            *KEYWORD_ALIAS,
+            *KEYWORD_CONST,
+            *KEYWORD_STATIC,
+            *KEYWORD_MODULE,
+            *KEYWORD_FN,
+            *KEYWORD_STRUCT,
+            *KEYWORD_ENUM,
+            *KEYWORD_INTERFACE,
+        ];
+
+        for keyword in keywords {
+            if !allowed.contains(&keyword) && self.lookup_keyword(offset, keyword) {
+                return true;
+            }
+        }
+
+        // extern and impl blocks have no visibility
+        self.lookup_keyword(0, *KEYWORD_EXTERN)
+            || (!allowed.contains(&*KEYWORD_IMPL) && self.lookup_keyword(0, *KEYWORD_IMPL))
+    }
+
+    /// Parses a module item in a place that does not accept it, e.g. `const` in an impl block.
+    pub fn parse_disallowed_module_item(&mut self, place: &str) {
+        let pos = self.current_pos();
+        let _ = self.parse_module_item();
+
+        self.diagnostics().error(
+            exc_diagnostic::error_codes::ITEM_NOT_ALLOWED,
+            self.make_span(pos),
+            format!("this item is not allowed in {}", place),
+        );
+    }
+
+    pub fn parse_doc_comment(&mut self) -> Result<ASTDocComment, ()> {
+        let (id, pos) = self.new_node();
+        let mut lines = vec![self.doc_comment_or_err()?];
+
+        while let Some(line) = self.doc_comment() {
+            lines.push(line);
+        }
+
+        Ok(ASTDocComment {
+            id,
+            span: self.make_span(pos),
+            lines,
+        })
+    }
+
+    /// Parses a doc comment that has no item to document, e.g. in a statement block.
+    pub fn parse_unattached_doc_comment(&mut self) {
+        if let Ok(doc_comment) = self.parse_doc_comment() {
+            self.diagnostics().error_sub(
+                exc_diagnostic::error_codes::DOC_COMMENT_NOT_ATTACHED,
+                doc_comment.span,
+                format!("doc comment not attached to an item"),
+                vec![self.diagnostics().sub_hint(
+                    doc_comment.span,
+                    format!("use `#` for a plain comment instead"),
+                )],
+            );
+        }
+    }
+
+    pub fn parse_attributes(&mut self) -> Result<Vec<ASTAttribute>, ()> {
+        let mut attributes = Vec::new();
+
+        while self.lookup_kind(0, TokenKind::At) {
+            attributes.push(self.parse_attribute()?);
+        }
+
+        Ok(attributes)
+    }
+
+    /// Parses attributes in a place that does not accept them, e.g. before a statement.
+    pub fn parse_disallowed_attributes(&mut self, place: &str) {
+        let attributes = match self.parse_attributes() {
+            Ok(attributes) => attributes,
+            Err(_) => return,
+        };
+
+        if let (Some(first), Some(last)) = (attributes.first(), attributes.last()) {
+            self.diagnostics().error(
+                exc_diagnostic::error_codes::ATTRIBUTE_NOT_ALLOWED,
+                first.span.to(last.span),
+                format!("attributes are not allowed on {}", place),
+            );
+        }
+    }
+
+    pub fn parse_attribute(&mut self) -> Result<ASTAttribute, ()> {
+        let (id, pos) = self.new_node();
+        let token_at = self.kind_or_err(TokenKind::At)?;
+        let identifier = self.identifier_or_err()?;
+        let args = if self.lookup_kind(0, TokenKind::OpenParen) {
+            Some(self.parse_attribute_args()?)
+        } else {
+            None
+        };
+
+        Ok(ASTAttribute {
+            id,
+            span: self.make_span(pos),
+            token_at,
+            identifier,
+            args,
+        })
+    }
+
+    pub fn parse_attribute_args(&mut self) -> Result<ASTAttributeArgs, ()> {
+        let (id, pos) = self.new_node();
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let mut args = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let arg = match self.parse_expr() {
+                Ok(arg) => arg,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_attribute_args_item(token)
+                            && before_expr(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    args.push(PunctuatedItem::Punctuated {
+                        item: arg,
+                        punctuation,
+                    });
+                }
+                None => {
+                    args.push(PunctuatedItem::NotPunctuated { item: arg });
+                    break;
+                }
+            }
+        }
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+        Ok(ASTAttributeArgs {
+            id,
+            span: self.make_span(pos),
+            token_paren_open,
+            args: Punctuated { items: args },
+            token_paren_close,
+        })
+    }
+
+    /// Returns the number of tokens of the visibility at the offset, e.g. 4 for `pub(super)`; 0 if there's none.
+    fn lookup_visibility_len(&mut self, offset: usize) -> usize {
+        if !self.lookup_keyword(offset, *KEYWORD_PUB) {
+            return 0;
+        }
+
+        if !self.lookup_kind(offset + 1, TokenKind::OpenParen) {
+            return 1;
+        }
+
+        let mut len = 2;
+
+        while self.lookup_identifier(offset + len)
+            || self.lookup_kind(offset + len, TokenKind::PathSep)
+        {
+            len += 1;
+        }
+
+        if self.lookup_kind(offset + len, TokenKind::CloseParen) {
+            len += 1;
+        }
+
+        len
+    }
+
+    pub fn parse_visibility(&mut self) -> Result<Option<ASTVisibility>, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_pub = match self.keyword(*KEYWORD_PUB) {
+            Some(keyword_pub) => keyword_pub,
+            None => return Ok(None),
+        };
+        let scope = if self.lookup_kind(0, TokenKind::OpenParen) {
+            Some(self.parse_visibility_scope()?)
+        } else {
+            None
+        };
+
+        Ok(Some(ASTVisibility {
+            id,
+            span: self.make_span(pos),
+            keyword_pub,
+            scope,
+        }))
+    }
+
+    pub fn parse_visibility_scope(&mut self) -> Result<ASTVisibilityScope, ()> {
+        let (id, pos) = self.new_node();
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let kind = if let Some(keyword) = self.keyword(*KEYWORD_SUPER) {
+            ASTVisibilityScopeKind::Super_(keyword)
+        } else if let Some(keyword) = self.keyword(*KEYWORD_MODULE) {
+            ASTVisibilityScopeKind::Module(keyword)
+        } else {
+            ASTVisibilityScopeKind::In(self.parse_visibility_scope_in()?)
+        };
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+        Ok(ASTVisibilityScope {
+            id,
+            span: self.make_span(pos),
+            token_paren_open,
             kind,
+            token_paren_close,
+        })
+    }
+
+    pub fn parse_visibility_scope_in(&mut self) -> Result<ASTVisibilityScopeIn, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_in = self.keyword_or_err(*KEYWORD_IN)?;
+
+        let mut path = Vec::new();
+
+        loop {
+            let segment = if let Some(keyword) = self.keyword(*KEYWORD_SELF) {
+                ASTUsePathPrefixSegmentKind::Self_(keyword)
+            } else if let Some(keyword) = self.keyword(*KEYWORD_SUPER) {
+                ASTUsePathPrefixSegmentKind::Super_(keyword)
+            } else {
+                ASTUsePathPrefixSegmentKind::Identifier(self.identifier_or_err()?)
+            };
+
+            if let Some(token) = self.kind(TokenKind::PathSep) {
+                path.push(PunctuatedItem::Punctuated {
+                    item: segment,
+                    punctuation: token,
+                });
+            } else {
+                path.push(PunctuatedItem::NotPunctuated { item: segment });
+                break;
+            }
+        }
+
+        Ok(ASTVisibilityScopeIn {
+            id,
+            span: self.make_span(pos),
+            keyword_in,
+            path: Punctuated { items: path },
         })
     }
 
     pub fn parse_use(&mut self) -> Result<ASTUse, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_use = self.keyword_or_err(*KEYWORD_USE)?;
         let path = self.parse_use_path()?;
         let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
@@ -120,7 +419,7 @@ where
         Ok(ASTUse {
             id,
             span: self.make_span(pos),
-            keyword_pub,
+            visibility,
             keyword_use,
             path,
             token_semicolon,
@@ -278,7 +577,7 @@ where
 
     pub fn parse_alias_def(&mut self) -> Result<ASTAliasDef, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_alias = self.keyword_or_err(*KEYWORD_ALIAS)?;
         let identifier = self.identifier_or_err()?;
         let token_assign = self.kind_or_err(TokenKind::Assign)?;
@@ -288,7 +587,7 @@ where
         Ok(ASTAliasDef {
             id,
             span: self.make_span(pos),
-            keyword_pub,
+            visibility,
             keyword_alias,
             identifier,
             token_assign,
@@ -297,9 +596,59 @@ where
         })
     }
 
+    pub fn parse_const_def(&mut self) -> Result<ASTConstDef, ()> {
+        let (id, pos) = self.new_node();
+        let visibility = self.parse_visibility()?;
+        let keyword_const = self.keyword_or_err(*KEYWORD_CONST)?;
+        let identifier = self.identifier_or_err()?;
+        let token_colon = self.kind_or_err(TokenKind::Colon)?;
+        let ty = self.parse_ty()?;
+        let token_assign = self.kind_or_err(TokenKind::Assign)?;
+        let expr = self.parse_expr()?;
+        let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+
+        Ok(ASTConstDef {
+            id,
+            span: self.make_span(pos),
+            visibility,
+            keyword_const,
+            identifier,
+            token_colon,
+            ty,
+            token_assign,
+            expr,
+            token_semicolon,
+        })
+    }
+
+    pub fn parse_static_def(&mut self) -> Result<ASTStaticDef, ()> {
+        let (id, pos) = self.new_node();
+        let visibility = self.parse_visibility()?;
+        let keyword_static = self.keyword_or_err(*KEYWORD_STATIC)?;
+        let identifier = self.identifier_or_err()?;
+        let token_colon = self.kind_or_err(TokenKind::Colon)?;
+        let ty = self.parse_ty()?;
+        let token_assign = self.kind_or_err(TokenKind::Assign)?;
+        let expr = self.parse_expr()?;
+        let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+
+        Ok(ASTStaticDef {
+            id,
+            span: self.make_span(pos),
+            visibility,
+            keyword_static,
+            identifier,
+            token_colon,
+            ty,
+            token_assign,
+            expr,
+            token_semicolon,
+        })
+    }
+
     pub fn parse_module_decl_or_def(&mut self) -> Result<ASTModuleItemKind, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_module = self.keyword_or_err(*KEYWORD_MODULE)?;
         let identifier = self.identifier_or_err()?;
 
@@ -326,7 +675,7 @@ where
                 ASTModuleDef {
                     id,
                     span: self.make_span(pos),
-                    keyword_pub,
+                    visibility,
                     keyword_module,
                     identifier,
                     token_brace_open,
@@ -342,7 +691,7 @@ where
                 ASTModuleDecl {
                     id,
                     span: self.make_span(pos),
-                    keyword_pub,
+                    visibility,
                     keyword_module,
                     identifier,
                     token_semicolon,
@@ -355,11 +704,18 @@ where
     pub fn parse_extern_block(&mut self) -> Result<ASTExternBlock, ()> {
         let (id, pos) = self.new_node();
         let keyword_extern = self.keyword_or_err(*KEYWORD_EXTERN)?;
+        let abi = if self.lookup_literal(0) {
+            Some(self.parse_extern_block_abi()?)
+        } else {
+            None
+        };
         let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
 
         let mut items = Vec::new();
 
         while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let pos = self.current_pos();
+
             match self.parse_extern_block_item() {
                 Ok(item) => {
                     items.push(item);
@@ -369,6 +725,11 @@ where
                     self.skip_tokens(|token| {
                         before_extern_block_item(token) && before_module_item(token)
                     });
+
+                    // eat at least one token, e.g. a stray `pub`, so that the loop makes progress
+                    if self.current_pos() == pos {
+                        self.skip_token();
+                    }
                 }
             }
         }
@@ -379,26 +740,45 @@ where
             id,
             span: self.make_span(pos),
             keyword_extern,
+            abi,
             token_brace_open,
             items,
             token_brace_close,
         })
     }
 
+    pub fn parse_extern_block_abi(&mut self) -> Result<ASTExternBlockAbi, ()> {
+        let (id, pos) = self.new_node();
+        let literal = self.literal_op_or_err()?;
+
+        Ok(ASTExternBlockAbi {
+            id,
+            span: self.make_span(pos),
+            literal,
+        })
+    }
+
     pub fn parse_extern_block_item(&mut self) -> Result<ASTExternBlockItem, ()> {
         let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        let attributes = self.parse_attributes()?;
 
-        let kind = if self.lookup_keyword(0, *KEYWORD_PROTOTYPE)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_PROTOTYPE))
-        {
+        if self.lookup_disallowed_module_item(&[*KEYWORD_FN, *KEYWORD_STRUCT, *KEYWORD_IMPL]) {
+            self.parse_disallowed_module_item("extern blocks");
+            return Err(());
+        }
+
+        let offset = self.lookup_visibility_len(0);
+
+        let kind = if self.lookup_keyword(offset, *KEYWORD_PROTOTYPE) {
             ASTExternBlockItemKind::PrototypeDef(self.parse_prototype_def()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_FN)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_FN))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_FN) {
             ASTExternBlockItemKind::FnDef(self.parse_fn_def()?.into())
-        } else if self.lookup_keyword(0, *KEYWORD_STRUCT)
-            || (self.lookup_keyword(0, *KEYWORD_PUB) && self.lookup_keyword(1, *KEYWORD_STRUCT))
-        {
+        } else if self.lookup_keyword(offset, *KEYWORD_STRUCT) {
             ASTExternBlockItemKind::StructDef(self.parse_struct_def()?.into())
         } else {
             ASTExternBlockItemKind::ImplBlock(self.parse_impl_block()?)
@@ -407,20 +787,29 @@ where
         Ok(ASTExternBlockItem {
             id,
             span: self.make_span(pos),
+            doc_comment,
+            attributes,
             kind,
         })
     }
 
     pub fn parse_prototype_def(&mut self) -> Result<ASTPrototypeDef, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_prototype = self.keyword_or_err(*KEYWORD_PROTOTYPE)?;
         let identifier = self.identifier_or_err()?;
         let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
 
         let mut params = Vec::new();
+        let mut token_ellipsis = None;
 
         while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            // the variadic parameter must be the last one
+            if let Some(token) = self.kind(TokenKind::Ellipsis) {
+                token_ellipsis = Some(token);
+                break;
+            }
+
             let param = match self.parse_fn_param() {
                 Ok(param) => param,
                 Err(_) => {
@@ -466,11 +855,12 @@ where
         Ok(ASTPrototypeDef {
             id,
             span: self.make_span(pos),
-            keyword_pub,
+            visibility,
             keyword_prototype,
             identifier,
             token_paren_open,
             params: Punctuated { items: params },
+            token_ellipsis,
             token_paren_close,
             result,
             token_semicolon,
@@ -479,7 +869,7 @@ where
 
     pub fn parse_fn_def(&mut self) -> Result<ASTFnDef, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_fn = self.keyword_or_err(*KEYWORD_FN)?;
         let identifier = self.identifier_or_err()?;
         let generic_param = if self.lookup_kind(0, TokenKind::Lt) {
@@ -542,7 +932,7 @@ where
         Ok(ASTFnDef {
             id,
             span: self.make_span(pos),
-            keyword_pub,
+            visibility,
             keyword_fn,
             identifier,
             generic_param,
@@ -557,14 +947,28 @@ where
 
     pub fn parse_fn_param(&mut self) -> Result<ASTFnParam, ()> {
         let (id, pos) = self.new_node();
-        let identifier = self.identifier_or_err()?;
+
+        if let Some(token_ellipsis) = self.kind(TokenKind::Ellipsis) {
+            self.diagnostics().error_sub(
+                exc_diagnostic::error_codes::VARIADIC_PARAM_NOT_ALLOWED,
+                token_ellipsis.span,
+                format!("variadic parameters are not allowed here"),
+                vec![self.diagnostics().sub_hint(
+                    token_ellipsis.span,
+                    format!("only prototypes in extern blocks can be variadic"),
+                )],
+            );
+            return Err(());
+        }
+
+        let pattern = self.parse_pattern()?;
         let token_colon = self.kind_or_err(TokenKind::Colon)?;
         let ty = self.parse_ty()?;
 
         Ok(ASTFnParam {
             id,
             span: self.make_span(pos),
-            identifier,
+            pattern,
             token_colon,
             ty,
         })
@@ -585,7 +989,7 @@ where
 
     pub fn parse_struct_def(&mut self) -> Result<ASTStructDef, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
+        let visibility = self.parse_visibility()?;
         let keyword_struct = self.keyword_or_err(*KEYWORD_STRUCT)?;
         let identifier = self.identifier_or_err()?;
         let generic_param = if self.lookup_kind(0, TokenKind::Lt) {
@@ -642,7 +1046,7 @@ where
         Ok(ASTStructDef {
             id,
             span: self.make_span(pos),
-            keyword_pub,
+            visibility,
             keyword_struct,
             identifier,
             generic_param,
@@ -655,6 +1059,12 @@ where
 
     pub fn parse_struct_def_field(&mut self) -> Result<ASTStructDefField, ()> {
         let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        let attributes = self.parse_attributes()?;
         let identifier = self.identifier_or_err()?;
         let token_colon = self.kind_or_err(TokenKind::Colon)?;
         let ty = self.parse_ty()?;
@@ -662,16 +1072,18 @@ where
         Ok(ASTStructDefField {
             id,
             span: self.make_span(pos),
+            doc_comment,
+            attributes,
             identifier,
             token_colon,
             ty,
         })
     }
 
-    pub fn parse_interface_def(&mut self) -> Result<ASTInterfaceDef, ()> {
+    pub fn parse_enum_def(&mut self) -> Result<ASTEnumDef, ()> {
         let (id, pos) = self.new_node();
-        let keyword_pub = self.keyword(*KEYWORD_PUB);
-        let keyword_interface = self.keyword_or_err(*KEYWORD_INTERFACE)?;
+        let visibility = self.parse_visibility()?;
+        let keyword_enum = self.keyword_or_err(*KEYWORD_ENUM)?;
         let identifier = self.identifier_or_err()?;
         let generic_param = if self.lookup_kind(0, TokenKind::Lt) {
             Some(self.parse_generic_param()?)
@@ -685,28 +1097,227 @@ where
         };
         let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
 
-        let mut items = Vec::new();
+        let mut variants = Vec::new();
 
         while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
-            match self.parse_interface_def_item() {
-                Ok(item) => {
-                    items.push(item);
-                }
+            let variant = match self.parse_enum_def_variant() {
+                Ok(variant) => variant,
                 Err(_) => {
                     // eat erroneous tokens
                     self.skip_tokens(|token| {
-                        before_interface_item(token) && before_module_item(token)
+                        before_enum_variants_item(token) && before_module_item(token)
                     });
-                }
-            }
-        }
-
-        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
 
-        Ok(ASTInterfaceDef {
-            id,
-            span: self.make_span(pos),
-            keyword_pub,
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    variants.push(PunctuatedItem::Punctuated {
+                        item: variant,
+                        punctuation,
+                    });
+                }
+                None => {
+                    variants.push(PunctuatedItem::NotPunctuated { item: variant });
+                    break;
+                }
+            }
+        }
+
+        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
+
+        Ok(ASTEnumDef {
+            id,
+            span: self.make_span(pos),
+            visibility,
+            keyword_enum,
+            identifier,
+            generic_param,
+            generic_where,
+            token_brace_open,
+            variants: Punctuated { items: variants },
+            token_brace_close,
+        })
+    }
+
+    pub fn parse_enum_def_variant(&mut self) -> Result<ASTEnumDefVariant, ()> {
+        let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        let identifier = self.identifier_or_err()?;
+        let kind = if self.lookup_kind(0, TokenKind::OpenParen) {
+            ASTEnumDefVariantKind::Tuple(self.parse_enum_def_variant_tuple()?)
+        } else if self.lookup_kind(0, TokenKind::OpenBrace) {
+            ASTEnumDefVariantKind::Struct(self.parse_enum_def_variant_struct()?)
+        } else {
+            ASTEnumDefVariantKind::Unit
+        };
+
+        Ok(ASTEnumDefVariant {
+            id,
+            span: self.make_span(pos),
+            doc_comment,
+            identifier,
+            kind,
+        })
+    }
+
+    pub fn parse_enum_def_variant_tuple(&mut self) -> Result<ASTEnumDefVariantTuple, ()> {
+        let (id, pos) = self.new_node();
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let mut items = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let item = match self.parse_ty() {
+                Ok(item) => item,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_enum_variant_tuple_item(token)
+                            && before_enum_variants_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    items.push(PunctuatedItem::Punctuated { item, punctuation });
+                }
+                None => {
+                    items.push(PunctuatedItem::NotPunctuated { item });
+                    break;
+                }
+            }
+        }
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+        Ok(ASTEnumDefVariantTuple {
+            id,
+            span: self.make_span(pos),
+            token_paren_open,
+            items: Punctuated { items },
+            token_paren_close,
+        })
+    }
+
+    pub fn parse_enum_def_variant_struct(&mut self) -> Result<ASTEnumDefVariantStruct, ()> {
+        let (id, pos) = self.new_node();
+        let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
+
+        let mut fields = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let field = match self.parse_struct_def_field() {
+                Ok(field) => field,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_struct_fields_item(token) && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    fields.push(PunctuatedItem::Punctuated {
+                        item: field,
+                        punctuation,
+                    });
+                }
+                None => {
+                    fields.push(PunctuatedItem::NotPunctuated { item: field });
+                    break;
+                }
+            }
+        }
+
+        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
+
+        Ok(ASTEnumDefVariantStruct {
+            id,
+            span: self.make_span(pos),
+            token_brace_open,
+            fields: Punctuated { items: fields },
+            token_brace_close,
+        })
+    }
+
+    pub fn parse_interface_def(&mut self) -> Result<ASTInterfaceDef, ()> {
+        let (id, pos) = self.new_node();
+        let visibility = self.parse_visibility()?;
+        let keyword_interface = self.keyword_or_err(*KEYWORD_INTERFACE)?;
+        let identifier = self.identifier_or_err()?;
+        let generic_param = if self.lookup_kind(0, TokenKind::Lt) {
+            Some(self.parse_generic_param()?)
+        } else {
+            None
+        };
+        let generic_where = if self.lookup_keyword(0, *KEYWORD_WHERE) {
+            Some(self.parse_generic_where()?)
+        } else {
+            None
+        };
+        let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
+
+        let mut items = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let pos = self.current_pos();
+
+            match self.parse_interface_def_item() {
+                Ok(item) => {
+                    items.push(item);
+                }
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_interface_item(token) && before_module_item(token)
+                    });
+
+                    // eat at least one token, e.g. a stray `pub`, so that the loop makes progress
+                    if self.current_pos() == pos {
+                        self.skip_token();
+                    }
+                }
+            }
+        }
+
+        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
+
+        Ok(ASTInterfaceDef {
+            id,
+            span: self.make_span(pos),
+            visibility,
             keyword_interface,
             identifier,
             generic_param,
@@ -719,11 +1330,26 @@ where
 
     pub fn parse_interface_def_item(&mut self) -> Result<ASTInterfaceDefItem, ()> {
         let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        if self.lookup_kind(0, TokenKind::At) {
+            self.parse_disallowed_attributes("interface items");
+        }
+
+        if self.lookup_disallowed_module_item(&[*KEYWORD_FN]) {
+            self.parse_disallowed_module_item("interfaces");
+            return Err(());
+        }
+
         let kind = ASTInterfaceDefItemKind::FnDecl(self.parse_interface_def_item_fn_decl()?);
 
         Ok(ASTInterfaceDefItem {
             id,
             span: self.make_span(pos),
+            doc_comment,
             kind,
         })
     }
@@ -828,6 +1454,8 @@ where
         let mut items = Vec::new();
 
         while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let pos = self.current_pos();
+
             match self.parse_impl_block_item() {
                 Ok(item) => {
                     items.push(item);
@@ -837,6 +1465,11 @@ where
                     self.skip_tokens(|token| {
                         before_impl_block_item(token) && before_module_item(token)
                     });
+
+                    // eat at least one token, e.g. a stray `pub`, so that the loop makes progress
+                    if self.current_pos() == pos {
+                        self.skip_token();
+                    }
                 }
             }
         }
@@ -872,11 +1505,25 @@ where
 
     pub fn parse_impl_block_item(&mut self) -> Result<ASTImplBlockItem, ()> {
         let (id, pos) = self.new_node();
+        let doc_comment = if self.lookup_doc_comment(0) {
+            Some(self.parse_doc_comment()?)
+        } else {
+            None
+        };
+        let attributes = self.parse_attributes()?;
+
+        if self.lookup_disallowed_module_item(&[*KEYWORD_FN]) {
+            self.parse_disallowed_module_item("impl blocks");
+            return Err(());
+        }
+
         let kind = ASTImplBlockItemKind::FnDef(self.parse_fn_def()?);
 
         Ok(ASTImplBlockItem {
             id,
             span: self.make_span(pos),
+            doc_comment,
+            attributes,
             kind,
         })
     }
@@ -1113,6 +1760,18 @@ where
             // eat semicolons if any
             self.skip_tokens(|token| token.kind == TokenKind::Semicolon);
 
+            // doc comments are attached to items only; report and ignore them here
+            if self.lookup_doc_comment(0) {
+                self.parse_unattached_doc_comment();
+                continue;
+            }
+
+            // same for attributes
+            if self.lookup_kind(0, TokenKind::At) {
+                self.parse_disallowed_attributes("statements");
+                continue;
+            }
+
             match self.parse_stmt() {
                 Ok(stmt) => {
                     stmts.push(stmt);
@@ -1142,7 +1801,7 @@ where
     pub fn parse_stmt_let(&mut self) -> Result<ASTStmtLet, ()> {
         let (id, pos) = self.new_node();
         let keyword_let = self.keyword_or_err(*KEYWORD_LET)?;
-        let identifier = self.identifier_or_err()?;
+        let pattern = self.parse_pattern()?;
         let ty = if self.lookup_kind(0, TokenKind::Colon) {
             Some(self.parse_stmt_let_ty()?)
         } else {
@@ -1159,7 +1818,7 @@ where
             id,
             span: self.make_span(pos),
             keyword_let,
-            identifier,
+            pattern,
             ty,
             expr,
             token_semicolon,
@@ -1192,43 +1851,53 @@ where
         })
     }
 
-    pub fn parse_stmt_if(&mut self) -> Result<ASTStmtIf, ()> {
+    pub fn parse_expr_if(&mut self) -> Result<ASTExpr, ()> {
         let (id, pos) = self.new_node();
         let keyword_if = self.keyword_or_err(*KEYWORD_IF)?;
-        let expr = self.parse_expr()?;
+        let expr = self.parse_expr_before_block()?;
         let stmt_block = self.parse_stmt_block()?;
 
         let mut else_ifs = Vec::new();
 
         while self.lookup_keyword(0, *KEYWORD_ELSE) && self.lookup_keyword(1, *KEYWORD_IF) {
-            else_ifs.push(self.parse_stmt_if_else_if()?);
+            else_ifs.push(self.parse_expr_if_else_if()?);
         }
 
         let else_ = if self.lookup_keyword(0, *KEYWORD_ELSE) {
-            Some(self.parse_stmt_if_else()?)
+            Some(self.parse_expr_if_else()?)
         } else {
             None
         };
 
-        Ok(ASTStmtIf {
+        Ok(self.wrap_expr_if(ASTExprIf {
             id,
             span: self.make_span(pos),
             keyword_if,
-            expr,
+            expr: Box::new(expr),
             stmt_block,
             else_ifs,
             else_,
-        })
+        }))
     }
 
-    pub fn parse_stmt_if_else_if(&mut self) -> Result<ASTStmtIfElseIf, ()> {
+    fn wrap_expr_if(&mut self, expr: ASTExprIf) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::If(expr),
+        }
+    }
+
+    pub fn parse_expr_if_else_if(&mut self) -> Result<ASTExprIfElseIf, ()> {
         let (id, pos) = self.new_node();
         let keyword_else = self.keyword_or_err(*KEYWORD_ELSE)?;
         let keyword_if = self.keyword_or_err(*KEYWORD_IF)?;
-        let expr = self.parse_expr()?;
+        let expr = self.parse_expr_before_block()?;
         let stmt_block = self.parse_stmt_block()?;
 
-        Ok(ASTStmtIfElseIf {
+        Ok(ASTExprIfElseIf {
             id,
             span: self.make_span(pos),
             keyword_else,
@@ -1238,12 +1907,12 @@ where
         })
     }
 
-    pub fn parse_stmt_if_else(&mut self) -> Result<ASTStmtIfElse, ()> {
+    pub fn parse_expr_if_else(&mut self) -> Result<ASTExprIfElse, ()> {
         let (id, pos) = self.new_node();
         let keyword_else = self.keyword_or_err(*KEYWORD_ELSE)?;
         let stmt_block = self.parse_stmt_block()?;
 
-        Ok(ASTStmtIfElse {
+        Ok(ASTExprIfElse {
             id,
             span: self.make_span(pos),
             keyword_else,
@@ -1251,43 +1920,119 @@ where
         })
     }
 
-    pub fn parse_stmt_loop(&mut self) -> Result<ASTStmtLoop, ()> {
+    pub fn parse_expr_loop(&mut self) -> Result<ASTExpr, ()> {
         let (id, pos) = self.new_node();
+        let label = self.parse_loop_label()?;
         let keyword_loop = self.keyword_or_err(*KEYWORD_LOOP)?;
         let stmt_block = self.parse_stmt_block()?;
 
-        Ok(ASTStmtLoop {
+        Ok(self.wrap_expr_loop(ASTExprLoop {
             id,
             span: self.make_span(pos),
+            label,
             keyword_loop,
             stmt_block,
-        })
+        }))
+    }
+
+    fn wrap_expr_loop(&mut self, expr: ASTExprLoop) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Loop(expr),
+        }
+    }
+
+    pub fn parse_expr_block(&mut self) -> Result<ASTExpr, ()> {
+        let block = self.parse_stmt_block()?;
+        Ok(self.wrap_expr_block(block))
+    }
+
+    fn wrap_expr_block(&mut self, expr: ASTStmtBlock) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Block(expr),
+        }
+    }
+
+    /// Parses the optional label of a loop, e.g. `'outer:` in `'outer: loop { ... }`.
+    pub fn parse_loop_label(&mut self) -> Result<Option<ASTLoopLabel>, ()> {
+        if !self.lookup_label(0) {
+            return Ok(None);
+        }
+
+        let (id, pos) = self.new_node();
+        let label = self.label_or_err()?;
+        let token_colon = self.kind_or_err(TokenKind::Colon)?;
+
+        Ok(Some(ASTLoopLabel {
+            id,
+            span: self.make_span(pos),
+            label,
+            token_colon,
+        }))
     }
 
     pub fn parse_stmt_while(&mut self) -> Result<ASTStmtWhile, ()> {
         let (id, pos) = self.new_node();
+        let label = self.parse_loop_label()?;
         let keyword_while = self.keyword_or_err(*KEYWORD_WHILE)?;
-        let expr = self.parse_expr()?;
+        let expr = self.parse_expr_before_block()?;
         let stmt_block = self.parse_stmt_block()?;
 
         Ok(ASTStmtWhile {
             id,
             span: self.make_span(pos),
+            label,
             keyword_while,
             expr,
             stmt_block,
         })
     }
 
-    pub fn parse_stmt_break(&mut self) -> Result<ASTStmtBreak, ()> {
+    pub fn parse_stmt_for(&mut self) -> Result<ASTStmtFor, ()> {
         let (id, pos) = self.new_node();
-        let keyword_break = self.keyword_or_err(*KEYWORD_BREAK)?;
-        let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+        let label = self.parse_loop_label()?;
+        let keyword_for = self.keyword_or_err(*KEYWORD_FOR)?;
+        let identifier = self.identifier_or_err()?;
+        let keyword_in = self.keyword_or_err(*KEYWORD_IN)?;
+        let expr = self.parse_expr_before_block()?;
+        let stmt_block = self.parse_stmt_block()?;
 
-        Ok(ASTStmtBreak {
+        Ok(ASTStmtFor {
             id,
             span: self.make_span(pos),
-            keyword_break,
+            label,
+            keyword_for,
+            identifier,
+            keyword_in,
+            expr,
+            stmt_block,
+        })
+    }
+
+    pub fn parse_stmt_break(&mut self) -> Result<ASTStmtBreak, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_break = self.keyword_or_err(*KEYWORD_BREAK)?;
+        let label = self.label();
+        let expr = if self.lookup_kind(0, TokenKind::Semicolon) {
+            None
+        } else {
+            Some(self.parse_expr()?)
+        };
+        let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+
+        Ok(ASTStmtBreak {
+            id,
+            span: self.make_span(pos),
+            keyword_break,
+            label,
+            expr,
             token_semicolon,
         })
     }
@@ -1295,12 +2040,14 @@ where
     pub fn parse_stmt_continue(&mut self) -> Result<ASTStmtContinue, ()> {
         let (id, pos) = self.new_node();
         let keyword_continue = self.keyword_or_err(*KEYWORD_CONTINUE)?;
+        let label = self.label();
         let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
 
         Ok(ASTStmtContinue {
             id,
             span: self.make_span(pos),
             keyword_continue,
+            label,
             token_semicolon,
         })
     }
@@ -1329,6 +2076,18 @@ where
         let expr = self.parse_expr()?;
 
         if self.lookup_assignment_op(0) {
+            if !expr.is_place() {
+                self.diagnostics().error_sub(
+                    exc_diagnostic::error_codes::INVALID_PLACE_EXPRESSION,
+                    expr.span,
+                    format!("invalid left-hand side of assignment"),
+                    vec![self.diagnostics().sub_hint(
+                        expr.span,
+                        format!("only variables, members, indexed elements and dereferences can be assigned to"),
+                    )],
+                );
+            }
+
             let operator = self.parse_stmt_assignment_operator()?;
             let operand_rhs = self.parse_expr()?;
             let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
@@ -1342,7 +2101,12 @@ where
                 token_semicolon,
             }))
         } else {
-            let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+            // the last expression of a block can omit the semicolon; it's the value of the block
+            let token_semicolon = if self.lookup_kind(0, TokenKind::CloseBrace) {
+                None
+            } else {
+                Some(self.kind_or_err(TokenKind::Semicolon)?)
+            };
 
             Ok(ASTStmtKind::Expr(ASTStmtExpr {
                 id,
@@ -1353,6 +2117,22 @@ where
         }
     }
 
+    /// Parses a statement of a block-like expression, i.e. a block, `if`, `loop` or `match`.
+    /// It ends at the closing brace, so the semicolon is optional;
+    /// e.g. `if x {} -1;` is two statements, not a subtraction.
+    pub fn parse_stmt_expr_block_like(&mut self) -> Result<ASTStmtExpr, ()> {
+        let (id, pos) = self.new_node();
+        let expr = self.parse_expr_block_like()?;
+        let token_semicolon = self.kind(TokenKind::Semicolon);
+
+        Ok(ASTStmtExpr {
+            id,
+            span: self.make_span(pos),
+            expr,
+            token_semicolon,
+        })
+    }
+
     pub fn parse_stmt_assignment_operator(&mut self) -> Result<ASTStmtAssignmentOperator, ()> {
         let (id, pos) = self.new_node();
         let token_operator = self.assignment_op_or_err()?;
@@ -1368,17 +2148,17 @@ where
 
     pub fn parse_stmt(&mut self) -> Result<ASTStmt, ()> {
         let (id, pos) = self.new_node();
+        // skip the label of a loop, e.g. `'outer:`
+        let offset = if self.lookup_label(0) { 2 } else { 0 };
 
-        let kind = if self.lookup_kind(0, TokenKind::OpenBrace) {
-            ASTStmtKind::Block(self.parse_stmt_block()?)
+        let kind = if self.lookup_keyword(offset, *KEYWORD_WHILE) {
+            ASTStmtKind::While(self.parse_stmt_while()?)
+        } else if self.lookup_keyword(offset, *KEYWORD_FOR) {
+            ASTStmtKind::For(self.parse_stmt_for()?)
+        } else if self.lookup_expr_block_like(0) {
+            ASTStmtKind::Expr(self.parse_stmt_expr_block_like()?)
         } else if self.lookup_keyword(0, *KEYWORD_LET) {
             ASTStmtKind::Let(self.parse_stmt_let()?)
-        } else if self.lookup_keyword(0, *KEYWORD_IF) {
-            ASTStmtKind::If(self.parse_stmt_if()?)
-        } else if self.lookup_keyword(0, *KEYWORD_LOOP) {
-            ASTStmtKind::Loop(self.parse_stmt_loop()?)
-        } else if self.lookup_keyword(0, *KEYWORD_WHILE) {
-            ASTStmtKind::While(self.parse_stmt_while()?)
         } else if self.lookup_keyword(0, *KEYWORD_BREAK) {
             ASTStmtKind::Break(self.parse_stmt_break()?)
         } else if self.lookup_keyword(0, *KEYWORD_CONTINUE) {
@@ -1398,12 +2178,92 @@ where
 
     pub fn parse_expr(&mut self) -> Result<ASTExpr, ()> {
         let prev = self.set_unglue_tokens(false);
+        let prev_allow_struct_literal = self.set_allow_struct_literal(true);
 
-        let expr = self.parse_expr_binary_1_compare()?;
+        let expr = self.parse_expr_range();
 
+        self.set_allow_struct_literal(prev_allow_struct_literal);
         self.set_unglue_tokens(prev);
 
-        Ok(expr)
+        expr
+    }
+
+    /// Parses an expression that is followed by a block, such as the condition of `if`.
+    /// Struct literals are allowed only in parentheses or other delimiters there.
+    pub fn parse_expr_before_block(&mut self) -> Result<ASTExpr, ()> {
+        let prev = self.set_unglue_tokens(false);
+        let prev_allow_struct_literal = self.set_allow_struct_literal(false);
+
+        let expr = self.parse_expr_range();
+
+        self.set_allow_struct_literal(prev_allow_struct_literal);
+        self.set_unglue_tokens(prev);
+
+        expr
+    }
+
+    pub fn parse_expr_range(&mut self) -> Result<ASTExpr, ()> {
+        let (id, pos) = self.new_node();
+        let start = if self.lookup_range_op(0) {
+            None
+        } else {
+            let expr = self.parse_expr_binary_1_compare()?;
+
+            if !self.lookup_range_op(0) {
+                return Ok(expr);
+            }
+
+            Some(Box::new(expr))
+        };
+
+        let operator = self.parse_expr_range_operator()?;
+        // the end of an exclusive range can be omitted, e.g. `a..` or `..`
+        let end = match operator.kind {
+            ASTExprRangeOperatorKind::Exclusive if !self.lookup_expr_range_end() => None,
+            _ => Some(Box::new(self.parse_expr_binary_1_compare()?)),
+        };
+
+        Ok(self.wrap_expr_range(ASTExprRange {
+            id,
+            span: self.make_span(pos),
+            start,
+            operator,
+            end,
+        }))
+    }
+
+    fn lookup_expr_range_end(&mut self) -> bool {
+        self.is_exists()
+            && !self.lookup_kind(0, TokenKind::Semicolon)
+            && !self.lookup_kind(0, TokenKind::Comma)
+            && !self.lookup_kind(0, TokenKind::CloseParen)
+            && !self.lookup_kind(0, TokenKind::CloseBracket)
+            && !self.lookup_kind(0, TokenKind::OpenBrace)
+            && !self.lookup_kind(0, TokenKind::CloseBrace)
+            && !self.lookup_interpolated_string_continuation(0)
+    }
+
+    fn wrap_expr_range(&mut self, expr: ASTExprRange) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Range(expr),
+        }
+    }
+
+    pub fn parse_expr_range_operator(&mut self) -> Result<ASTExprRangeOperator, ()> {
+        let (id, pos) = self.new_node();
+        let token_operator = self.range_op_or_err()?;
+        let kind = ASTExprRangeOperatorKind::from_token(&token_operator, self.diagnostics())?;
+
+        Ok(ASTExprRangeOperator {
+            id,
+            span: self.make_span(pos),
+            token_operator,
+            kind,
+        })
     }
 
     pub fn parse_expr_binary_1_compare(&mut self) -> Result<ASTExpr, ()> {
@@ -1664,6 +2524,8 @@ where
                 expr = self.parse_expr_call(expr)?;
             } else if self.lookup_kind(0, TokenKind::Dot) {
                 expr = self.parse_expr_member(expr)?;
+            } else if self.lookup_kind(0, TokenKind::OpenBracket) {
+                expr = self.parse_expr_index(expr)?;
             } else {
                 break;
             }
@@ -1751,7 +2613,13 @@ where
     pub fn parse_expr_member(&mut self, expr: ASTExpr) -> Result<ASTExpr, ()> {
         let (id, _) = self.new_node();
         let token_dot = self.kind_or_err(TokenKind::Dot)?;
-        let member = self.identifier_or_err()?;
+        let member = if self.lookup_identifier(0) {
+            ASTExprMemberKind::Named(self.identifier_or_err()?)
+        } else {
+            // `t.0.1` is lexed as `t`, `.` and `0.1`
+            self.split_float_literal();
+            ASTExprMemberKind::Positional(self.parse_expr_member_positional()?)
+        };
 
         Ok(self.wrap_expr_member(ASTExprMember {
             id,
@@ -1762,6 +2630,40 @@ where
         }))
     }
 
+    pub fn parse_expr_member_positional(&mut self) -> Result<ASTExprMemberPositional, ()> {
+        let (id, pos) = self.new_node();
+        let literal = self.literal_op_or_err()?;
+        let span = self.make_span(pos);
+
+        let content = literal.content.to_str();
+        // only the plain form is accepted, e.g. `1` or `10` but not `01` or `1_0`
+        let is_canonical = !content.contains('_') && (content == "0" || !content.starts_with('0'));
+
+        let index = match (literal.kind, literal.suffix, literal.value) {
+            (TokenLiteralKind::IntegerDecimal, None, Some(TokenLiteralValue::Integer(value)))
+                if is_canonical =>
+            {
+                value as usize
+            }
+            _ => {
+                self.diagnostics().error_sub(
+                    exc_diagnostic::error_codes::INVALID_POSITIONAL_MEMBER,
+                    span,
+                    format!("invalid positional member {}", literal.content),
+                    vec![self.diagnostics().sub_hint(
+                        span,
+                        format!(
+                            "positional members are decimal integers without a suffix, leading zeros or separators, e.g. `0`"
+                        ),
+                    )],
+                );
+                return Err(());
+            }
+        };
+
+        Ok(ASTExprMemberPositional { id, span, index })
+    }
+
     fn wrap_expr_member(&mut self, expr: ASTExprMember) -> ASTExpr {
         let (id, _) = self.new_node();
 
@@ -1772,29 +2674,131 @@ where
         }
     }
 
+    pub fn parse_expr_index(&mut self, expr: ASTExpr) -> Result<ASTExpr, ()> {
+        let (id, _) = self.new_node();
+        let token_bracket_open = self.kind_or_err(TokenKind::OpenBracket)?;
+        let index = self.parse_expr()?;
+        let token_bracket_close = self.kind_or_err(TokenKind::CloseBracket)?;
+
+        Ok(self.wrap_expr_index(ASTExprIndex {
+            id,
+            span: self.make_span(expr.span.low),
+            expr: Box::new(expr),
+            token_bracket_open,
+            index: Box::new(index),
+            token_bracket_close,
+        }))
+    }
+
+    fn wrap_expr_index(&mut self, expr: ASTExprIndex) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Index(expr),
+        }
+    }
+
     pub fn parse_expr_paren_or_single_item(&mut self) -> Result<ASTExpr, ()> {
         if self.lookup_kind(0, TokenKind::OpenParen) {
-            self.parse_expr_paren()
+            self.parse_expr_paren_or_tuple()
+        } else if self.lookup_kind(0, TokenKind::OpenBracket) {
+            self.parse_expr_array_literal_or_repeat()
         } else {
             self.parse_expr_single_item()
         }
     }
 
-    pub fn parse_expr_paren(&mut self) -> Result<ASTExpr, ()> {
+    pub fn parse_expr_paren_or_tuple(&mut self) -> Result<ASTExpr, ()> {
         let (id, pos) = self.new_node();
         let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
-        let expr = self.parse_expr()?;
+
+        let mut elements = Vec::new();
+
+        if !self.lookup_kind(0, TokenKind::CloseParen) {
+            let expr = self.parse_expr()?;
+
+            // a single expression without a trailing comma is parenthesized, not a tuple
+            match self.kind(TokenKind::Comma) {
+                Some(punctuation) => {
+                    elements.push(PunctuatedItem::Punctuated {
+                        item: expr,
+                        punctuation,
+                    });
+                }
+                None => {
+                    let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+                    return Ok(self.wrap_expr_paren(ASTExprParen {
+                        id,
+                        span: self.make_span(pos),
+                        token_paren_open,
+                        expr: Box::new(expr),
+                        token_paren_close,
+                    }));
+                }
+            }
+        }
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let element = match self.parse_expr() {
+                Ok(element) => element,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_expr_tuple_item(token)
+                            && before_expr(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    elements.push(PunctuatedItem::Punctuated {
+                        item: element,
+                        punctuation,
+                    });
+                }
+                None => {
+                    elements.push(PunctuatedItem::NotPunctuated { item: element });
+                    break;
+                }
+            }
+        }
+
         let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
 
-        Ok(self.wrap_expr_paren(ASTExprParen {
+        Ok(self.wrap_expr_tuple(ASTExprTuple {
             id,
             span: self.make_span(pos),
             token_paren_open,
-            expr: Box::new(expr),
+            elements: Punctuated { items: elements },
             token_paren_close,
         }))
     }
 
+    fn wrap_expr_tuple(&mut self, expr: ASTExprTuple) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Tuple(expr),
+        }
+    }
+
     fn wrap_expr_paren(&mut self, expr: ASTExprParen) -> ASTExpr {
         let (id, _) = self.new_node();
 
@@ -1805,15 +2809,41 @@ where
         }
     }
 
+    fn lookup_expr_block_like(&mut self, offset: usize) -> bool {
+        self.lookup_kind(offset, TokenKind::OpenBrace)
+            || self.lookup_keyword(offset, *KEYWORD_IF)
+            || self.lookup_keyword(offset, *KEYWORD_LOOP)
+            || self.lookup_keyword(offset, *KEYWORD_MATCH)
+            || self.lookup_label(offset)
+    }
+
+    pub fn parse_expr_block_like(&mut self) -> Result<ASTExpr, ()> {
+        if self.lookup_keyword(0, *KEYWORD_IF) {
+            self.parse_expr_if()
+        } else if self.lookup_keyword(0, *KEYWORD_LOOP) || self.lookup_label(0) {
+            self.parse_expr_loop()
+        } else if self.lookup_keyword(0, *KEYWORD_MATCH) {
+            self.parse_expr_match()
+        } else {
+            self.parse_expr_block()
+        }
+    }
+
     pub fn parse_expr_single_item(&mut self) -> Result<ASTExpr, ()> {
-        if self.lookup_identifier(0) {
+        if self.lookup_expr_block_like(0) {
+            self.parse_expr_block_like()
+        } else if self.lookup_keyword(0, *KEYWORD_FN) {
+            self.parse_expr_closure()
+        } else if self.lookup_identifier(0) {
             let path = self.parse_path()?;
 
-            if self.lookup_kind(0, TokenKind::OpenBrace) {
+            if self.is_struct_literal_allowed() && self.lookup_kind(0, TokenKind::OpenBrace) {
                 self.parse_expr_struct_literal(path)
             } else {
                 self.parse_expr_path(path)
             }
+        } else if self.lookup_interpolated_string_head(0) {
+            self.parse_expr_interpolated_string()
         } else {
             self.parse_expr_literal()
         }
@@ -1840,6 +2870,61 @@ where
         }
     }
 
+    pub fn parse_expr_interpolated_string(&mut self) -> Result<ASTExpr, ()> {
+        let (id, pos) = self.new_node();
+        let token_head = self.interpolated_string_head_or_err()?;
+
+        let mut interpolations = Vec::new();
+        let mut is_end = match token_head.kind {
+            TokenKind::InterpolatedString { part, .. } => part.is_end(),
+            _ => unreachable!(),
+        };
+
+        while !is_end {
+            let interpolation = self.parse_expr_interpolated_string_interpolation()?;
+
+            is_end = match interpolation.token_tail.kind {
+                TokenKind::InterpolatedString { part, .. } => part.is_end(),
+                _ => unreachable!(),
+            };
+            interpolations.push(interpolation);
+        }
+
+        Ok(
+            self.wrap_expr_interpolated_string(ASTExprInterpolatedString {
+                id,
+                span: self.make_span(pos),
+                token_head,
+                interpolations,
+            }),
+        )
+    }
+
+    fn wrap_expr_interpolated_string(&mut self, expr: ASTExprInterpolatedString) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::InterpolatedString(expr),
+        }
+    }
+
+    pub fn parse_expr_interpolated_string_interpolation(
+        &mut self,
+    ) -> Result<ASTExprInterpolatedStringInterpolation, ()> {
+        let (id, pos) = self.new_node();
+        let expr = self.parse_expr()?;
+        let token_tail = self.interpolated_string_continuation_or_err()?;
+
+        Ok(ASTExprInterpolatedStringInterpolation {
+            id,
+            span: self.make_span(pos),
+            expr,
+            token_tail,
+        })
+    }
+
     pub fn parse_expr_struct_literal(&mut self, path: ASTPath) -> Result<ASTExpr, ()> {
         let (id, _) = self.new_node();
         let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
@@ -1920,32 +3005,651 @@ where
         })
     }
 
-    pub fn parse_expr_path(&mut self, path: ASTPath) -> Result<ASTExpr, ()> {
-        let (id, _) = self.new_node();
+    pub fn parse_expr_array_literal_or_repeat(&mut self) -> Result<ASTExpr, ()> {
+        let (id, pos) = self.new_node();
+        let token_bracket_open = self.kind_or_err(TokenKind::OpenBracket)?;
 
-        Ok(self.wrap_expr_path(ASTExprPath {
+        let mut elements = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBracket) {
+            let element = match self.parse_expr() {
+                Ok(element) => element,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_expr_array_literal_item(token)
+                            && before_expr(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            if elements.is_empty() && self.lookup_kind(0, TokenKind::Semicolon) {
+                let token_semicolon = self.kind_or_err(TokenKind::Semicolon)?;
+                let count = self.parse_expr()?;
+                let token_bracket_close = self.kind_or_err(TokenKind::CloseBracket)?;
+
+                return Ok(self.wrap_expr_array_repeat(ASTExprArrayRepeat {
+                    id,
+                    span: self.make_span(pos),
+                    token_bracket_open,
+                    expr: Box::new(element),
+                    token_semicolon,
+                    count: Box::new(count),
+                    token_bracket_close,
+                }));
+            }
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    elements.push(PunctuatedItem::Punctuated {
+                        item: element,
+                        punctuation,
+                    });
+                }
+                None => {
+                    elements.push(PunctuatedItem::NotPunctuated { item: element });
+                    break;
+                }
+            }
+        }
+
+        let token_bracket_close = self.kind_or_err(TokenKind::CloseBracket)?;
+
+        Ok(self.wrap_expr_array_literal(ASTExprArrayLiteral {
             id,
-            span: self.make_span(path.span.low),
-            path,
+            span: self.make_span(pos),
+            token_bracket_open,
+            elements: Punctuated { items: elements },
+            token_bracket_close,
         }))
     }
 
-    fn wrap_expr_path(&mut self, expr: ASTExprPath) -> ASTExpr {
+    fn wrap_expr_array_literal(&mut self, expr: ASTExprArrayLiteral) -> ASTExpr {
         let (id, _) = self.new_node();
 
         ASTExpr {
             id,
             span: expr.span,
-            kind: ASTExprKind::Path(expr),
+            kind: ASTExprKind::ArrayLiteral(expr),
         }
     }
 
-    pub fn parse_path(&mut self) -> Result<ASTPath, ()> {
-        let (id, pos) = self.new_node();
-
-        let mut segments = vec![PunctuatedItem::NotPunctuated {
-            item: self.parse_path_segment()?,
-        }];
+    fn wrap_expr_array_repeat(&mut self, expr: ASTExprArrayRepeat) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::ArrayRepeat(expr),
+        }
+    }
+
+    pub fn parse_expr_match(&mut self) -> Result<ASTExpr, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_match = self.keyword_or_err(*KEYWORD_MATCH)?;
+        let expr = self.parse_expr_before_block()?;
+        let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
+
+        let mut arms = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let arm = match self.parse_expr_match_arm() {
+                Ok(arm) => arm,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_expr_match_arm_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    arms.push(PunctuatedItem::Punctuated {
+                        item: arm,
+                        punctuation,
+                    });
+                }
+                None => {
+                    // the comma can be omitted after a block
+                    let is_block = matches!(arm.body, ASTExprMatchArmBody::Block(_));
+                    arms.push(PunctuatedItem::NotPunctuated { item: arm });
+
+                    if !is_block {
+                        break;
+                    }
+                }
+            }
+        }
+
+        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
+
+        Ok(self.wrap_expr_match(ASTExprMatch {
+            id,
+            span: self.make_span(pos),
+            keyword_match,
+            expr: Box::new(expr),
+            token_brace_open,
+            arms: Punctuated { items: arms },
+            token_brace_close,
+        }))
+    }
+
+    fn wrap_expr_match(&mut self, expr: ASTExprMatch) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Match(expr),
+        }
+    }
+
+    pub fn parse_expr_closure(&mut self) -> Result<ASTExpr, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_fn = self.keyword_or_err(*KEYWORD_FN)?;
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let mut params = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let param = match self.parse_fn_param() {
+                Ok(param) => param,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_fn_params_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    params.push(PunctuatedItem::Punctuated {
+                        item: param,
+                        punctuation,
+                    });
+                }
+                None => {
+                    params.push(PunctuatedItem::NotPunctuated { item: param });
+                    break;
+                }
+            }
+        }
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+        let result = if self.lookup_kind(0, TokenKind::Arrow) {
+            Some(self.parse_fn_result()?)
+        } else {
+            None
+        };
+        let stmt_block = self.parse_stmt_block()?;
+
+        Ok(self.wrap_expr_closure(ASTExprClosure {
+            id,
+            span: self.make_span(pos),
+            keyword_fn,
+            token_paren_open,
+            params: Punctuated { items: params },
+            token_paren_close,
+            result,
+            stmt_block,
+        }))
+    }
+
+    fn wrap_expr_closure(&mut self, expr: ASTExprClosure) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Closure(expr),
+        }
+    }
+
+    pub fn parse_expr_match_arm(&mut self) -> Result<ASTExprMatchArm, ()> {
+        let (id, pos) = self.new_node();
+        let pattern = self.parse_pattern()?;
+        let token_fat_arrow = self.kind_or_err(TokenKind::FatArrow)?;
+        let body = if self.lookup_kind(0, TokenKind::OpenBrace) {
+            ASTExprMatchArmBody::Block(self.parse_stmt_block()?)
+        } else {
+            ASTExprMatchArmBody::Expr(Box::new(self.parse_expr()?))
+        };
+
+        Ok(ASTExprMatchArm {
+            id,
+            span: self.make_span(pos),
+            pattern,
+            token_fat_arrow,
+            body,
+        })
+    }
+
+    pub fn parse_pattern(&mut self) -> Result<ASTPattern, ()> {
+        let (id, pos) = self.new_node();
+        let kind = if self.lookup_keyword(0, *KEYWORD_UNDERSCORE) {
+            ASTPatternKind::Wildcard(self.parse_pattern_wildcard()?)
+        } else if self.lookup_identifier(0)
+            && !self.lookup_kind(1, TokenKind::PathSep)
+            && !self.lookup_kind(1, TokenKind::Lt)
+            && !self.lookup_kind(1, TokenKind::OpenBrace)
+            && !self.lookup_kind(1, TokenKind::OpenParen)
+        {
+            ASTPatternKind::Binding(self.parse_pattern_binding()?)
+        } else if self.lookup_identifier(0) {
+            self.parse_pattern_path_or_struct()?
+        } else if self.lookup_kind(0, TokenKind::OpenParen) {
+            ASTPatternKind::Tuple(self.parse_pattern_tuple()?)
+        } else if self.lookup_kind(0, TokenKind::OpenBracket) {
+            ASTPatternKind::Array(self.parse_pattern_array()?)
+        } else {
+            self.parse_pattern_literal_or_range()?
+        };
+
+        Ok(ASTPattern {
+            id,
+            span: self.make_span(pos),
+            kind,
+        })
+    }
+
+    pub fn parse_pattern_wildcard(&mut self) -> Result<ASTPatternWildcard, ()> {
+        let (id, pos) = self.new_node();
+        let keyword_underscore = self.keyword_or_err(*KEYWORD_UNDERSCORE)?;
+
+        Ok(ASTPatternWildcard {
+            id,
+            span: self.make_span(pos),
+            keyword_underscore,
+        })
+    }
+
+    pub fn parse_pattern_binding(&mut self) -> Result<ASTPatternBinding, ()> {
+        let (id, pos) = self.new_node();
+        let identifier = self.identifier_or_err()?;
+
+        Ok(ASTPatternBinding {
+            id,
+            span: self.make_span(pos),
+            identifier,
+        })
+    }
+
+    pub fn parse_pattern_literal_or_range(&mut self) -> Result<ASTPatternKind, ()> {
+        let (id, pos) = self.new_node();
+        let start = if self.lookup_range_op(0) {
+            None
+        } else {
+            let literal = self.parse_pattern_literal()?;
+
+            if !self.lookup_range_op(0) {
+                return Ok(ASTPatternKind::Literal(literal));
+            }
+
+            Some(literal)
+        };
+
+        let operator = self.parse_expr_range_operator()?;
+        // the end of an exclusive range can be omitted if it has the start, e.g. `0..`
+        let end = match operator.kind {
+            ASTExprRangeOperatorKind::Exclusive
+                if start.is_some() && !self.lookup_pattern_literal(0) =>
+            {
+                None
+            }
+            _ => Some(self.parse_pattern_literal()?),
+        };
+
+        Ok(ASTPatternKind::Range(ASTPatternRange {
+            id,
+            span: self.make_span(pos),
+            start,
+            operator,
+            end,
+        }))
+    }
+
+    fn lookup_pattern_literal(&mut self, offset: usize) -> bool {
+        self.lookup_kind(offset, TokenKind::Sub) || self.lookup_literal(offset)
+    }
+
+    pub fn parse_pattern_literal(&mut self) -> Result<ASTPatternLiteral, ()> {
+        let (id, pos) = self.new_node();
+        let token_minus = self.kind(TokenKind::Sub);
+        let literal = self.literal_op_or_err()?;
+
+        Ok(ASTPatternLiteral {
+            id,
+            span: self.make_span(pos),
+            token_minus,
+            literal,
+        })
+    }
+
+    pub fn parse_pattern_path_or_struct(&mut self) -> Result<ASTPatternKind, ()> {
+        let (id, pos) = self.new_node();
+        let path = self.parse_path()?;
+
+        if self.lookup_kind(0, TokenKind::OpenBrace) {
+            return Ok(ASTPatternKind::Struct(self.parse_pattern_struct(path)?));
+        }
+
+        if self.lookup_kind(0, TokenKind::OpenParen) {
+            return Ok(ASTPatternKind::TupleStruct(
+                self.parse_pattern_tuple_struct(path)?,
+            ));
+        }
+
+        Ok(ASTPatternKind::Path(ASTPatternPath {
+            id,
+            span: self.make_span(pos),
+            path,
+        }))
+    }
+
+    pub fn parse_pattern_struct(&mut self, path: ASTPath) -> Result<ASTPatternStruct, ()> {
+        let (id, _) = self.new_node();
+        let token_brace_open = self.kind_or_err(TokenKind::OpenBrace)?;
+
+        let mut fields = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBrace) {
+            let field = match self.parse_pattern_struct_field() {
+                Ok(field) => field,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_pattern_struct_field_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    fields.push(PunctuatedItem::Punctuated {
+                        item: field,
+                        punctuation,
+                    });
+                }
+                None => {
+                    fields.push(PunctuatedItem::NotPunctuated { item: field });
+                    break;
+                }
+            }
+        }
+
+        let token_brace_close = self.kind_or_err(TokenKind::CloseBrace)?;
+
+        Ok(ASTPatternStruct {
+            id,
+            span: self.make_span(path.span.low),
+            path,
+            token_brace_open,
+            fields: Punctuated { items: fields },
+            token_brace_close,
+        })
+    }
+
+    pub fn parse_pattern_struct_field(&mut self) -> Result<ASTPatternStructField, ()> {
+        let (id, pos) = self.new_node();
+        let identifier = self.identifier_or_err()?;
+        let pattern = if self.lookup_kind(0, TokenKind::Colon) {
+            Some(self.parse_pattern_struct_field_pattern()?)
+        } else {
+            None
+        };
+
+        Ok(ASTPatternStructField {
+            id,
+            span: self.make_span(pos),
+            identifier,
+            pattern,
+        })
+    }
+
+    pub fn parse_pattern_struct_field_pattern(
+        &mut self,
+    ) -> Result<ASTPatternStructFieldPattern, ()> {
+        let (id, pos) = self.new_node();
+        let token_colon = self.kind_or_err(TokenKind::Colon)?;
+        let pattern = self.parse_pattern()?;
+
+        Ok(ASTPatternStructFieldPattern {
+            id,
+            span: self.make_span(pos),
+            token_colon,
+            pattern,
+        })
+    }
+
+    pub fn parse_pattern_tuple_struct(
+        &mut self,
+        path: ASTPath,
+    ) -> Result<ASTPatternTupleStruct, ()> {
+        let (id, _) = self.new_node();
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let mut fields = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let field = match self.parse_pattern() {
+                Ok(field) => field,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_pattern_tuple_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    fields.push(PunctuatedItem::Punctuated {
+                        item: field,
+                        punctuation,
+                    });
+                }
+                None => {
+                    fields.push(PunctuatedItem::NotPunctuated { item: field });
+                    break;
+                }
+            }
+        }
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+        Ok(ASTPatternTupleStruct {
+            id,
+            span: self.make_span(path.span.low),
+            path,
+            token_paren_open,
+            fields: Punctuated { items: fields },
+            token_paren_close,
+        })
+    }
+
+    pub fn parse_pattern_tuple(&mut self) -> Result<ASTPatternTuple, ()> {
+        let (id, pos) = self.new_node();
+        let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
+
+        let mut items = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let item = match self.parse_pattern() {
+                Ok(item) => item,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_pattern_tuple_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    items.push(PunctuatedItem::Punctuated { item, punctuation });
+                }
+                None => {
+                    items.push(PunctuatedItem::NotPunctuated { item });
+                    break;
+                }
+            }
+        }
+
+        let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+        Ok(ASTPatternTuple {
+            id,
+            span: self.make_span(pos),
+            token_paren_open,
+            items: Punctuated { items },
+            token_paren_close,
+        })
+    }
+
+    pub fn parse_pattern_array(&mut self) -> Result<ASTPatternArray, ()> {
+        let (id, pos) = self.new_node();
+        let token_bracket_open = self.kind_or_err(TokenKind::OpenBracket)?;
+
+        let mut items = Vec::new();
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseBracket) {
+            let item = match self.parse_pattern() {
+                Ok(item) => item,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_pattern_array_item(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    items.push(PunctuatedItem::Punctuated { item, punctuation });
+                }
+                None => {
+                    items.push(PunctuatedItem::NotPunctuated { item });
+                    break;
+                }
+            }
+        }
+
+        let token_bracket_close = self.kind_or_err(TokenKind::CloseBracket)?;
+
+        Ok(ASTPatternArray {
+            id,
+            span: self.make_span(pos),
+            token_bracket_open,
+            items: Punctuated { items },
+            token_bracket_close,
+        })
+    }
+
+    pub fn parse_expr_path(&mut self, path: ASTPath) -> Result<ASTExpr, ()> {
+        let (id, _) = self.new_node();
+
+        Ok(self.wrap_expr_path(ASTExprPath {
+            id,
+            span: self.make_span(path.span.low),
+            path,
+        }))
+    }
+
+    fn wrap_expr_path(&mut self, expr: ASTExprPath) -> ASTExpr {
+        let (id, _) = self.new_node();
+
+        ASTExpr {
+            id,
+            span: expr.span,
+            kind: ASTExprKind::Path(expr),
+        }
+    }
+
+    pub fn parse_path(&mut self) -> Result<ASTPath, ()> {
+        let (id, pos) = self.new_node();
+
+        let mut segments = vec![PunctuatedItem::NotPunctuated {
+            item: self.parse_path_segment()?,
+        }];
 
         while self.lookup_kind(0, TokenKind::PathSep) {
             let segment = segments.pop().unwrap();
@@ -1990,7 +3694,7 @@ where
 
         let (id, pos) = self.new_node();
         let kind = if self.lookup_kind(0, TokenKind::OpenParen) {
-            ASTTyKind::Paren(self.parse_ty_paren()?)
+            self.parse_ty_paren_or_tuple()?
         } else if self.lookup_kind(0, TokenKind::OpenBracket) {
             self.parse_ty_span_or_array()?
         } else if self.lookup_keyword(0, *KEYWORD_FN) {
@@ -2008,19 +3712,80 @@ where
         })
     }
 
-    pub fn parse_ty_paren(&mut self) -> Result<ASTTyParen, ()> {
+    pub fn parse_ty_paren_or_tuple(&mut self) -> Result<ASTTyKind, ()> {
         let (id, pos) = self.new_node();
         let token_paren_open = self.kind_or_err(TokenKind::OpenParen)?;
-        let ty = self.parse_ty()?;
+
+        let mut items = Vec::new();
+
+        if !self.lookup_kind(0, TokenKind::CloseParen) {
+            let ty = self.parse_ty()?;
+
+            // a single type without a trailing comma is parenthesized, not a tuple
+            match self.kind(TokenKind::Comma) {
+                Some(punctuation) => {
+                    items.push(PunctuatedItem::Punctuated {
+                        item: ty,
+                        punctuation,
+                    });
+                }
+                None => {
+                    let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
+
+                    return Ok(ASTTyKind::Paren(ASTTyParen {
+                        id,
+                        span: self.make_span(pos),
+                        token_paren_open,
+                        ty: Box::new(ty),
+                        token_paren_close,
+                    }));
+                }
+            }
+        }
+
+        while self.is_exists() && !self.lookup_kind(0, TokenKind::CloseParen) {
+            let item = match self.parse_ty() {
+                Ok(item) => item,
+                Err(_) => {
+                    // eat erroneous tokens
+                    self.skip_tokens(|token| {
+                        before_ty_tuple_item(token)
+                            && before_expr(token)
+                            && before_impl_block_item(token)
+                            && before_extern_block_item(token)
+                            && before_module_item(token)
+                    });
+
+                    if self.kind(TokenKind::Comma).is_some() {
+                        continue; // comma found, continue
+                    } else {
+                        break; // comma not found, break
+                    }
+                }
+            };
+
+            let punctuation = self.kind(TokenKind::Comma);
+
+            match punctuation {
+                Some(punctuation) => {
+                    items.push(PunctuatedItem::Punctuated { item, punctuation });
+                }
+                None => {
+                    items.push(PunctuatedItem::NotPunctuated { item });
+                    break;
+                }
+            }
+        }
+
         let token_paren_close = self.kind_or_err(TokenKind::CloseParen)?;
 
-        Ok(ASTTyParen {
+        Ok(ASTTyKind::Tuple(ASTTyTuple {
             id,
             span: self.make_span(pos),
             token_paren_open,
-            ty: Box::new(ty),
+            items: Punctuated { items },
             token_paren_close,
-        })
+        }))
     }
 
     pub fn parse_ty_span_or_array(&mut self) -> Result<ASTTyKind, ()> {
@@ -1,4 +1,7 @@
-use crate::{Id, NodeId, NodeIdAllocator, Token, TokenKind, TokenLiteral, TokenType};
+use crate::{
+    Id, NodeId, NodeIdAllocator, Token, TokenKind, TokenLiteral, TokenLiteralKind,
+    TokenLiteralValue, TokenType,
+};
 use exc_diagnostic::DiagnosticsSender;
 use exc_span::{Pos, Span};
 use exc_symbol::Symbol;
@@ -9,6 +12,7 @@ where
     T: Iterator<Item = Token>,
 {
     unglue_tokens: bool,
+    allow_struct_literal: bool,
     token_stream: T,
     token_buffer: VecDeque<Token>,
     expected: Vec<TokenType>,
@@ -28,6 +32,7 @@ where
     ) -> Self {
         Self {
             unglue_tokens: false,
+            allow_struct_literal: true,
             token_stream,
             token_buffer: VecDeque::new(),
             expected: Vec::new(),
@@ -47,6 +52,18 @@ where
         prev
     }
 
+    /// Struct literals are not allowed right before a block, e.g. in `if x { ... }`;
+    /// the `{` begins the block there.
+    pub fn set_allow_struct_literal(&mut self, allow_struct_literal: bool) -> bool {
+        let prev = self.allow_struct_literal;
+        self.allow_struct_literal = allow_struct_literal;
+        prev
+    }
+
+    pub fn is_struct_literal_allowed(&self) -> bool {
+        self.allow_struct_literal
+    }
+
     pub fn is_exists(&mut self) -> bool {
         self.fetch_tokens(1);
         !self.token_buffer.is_empty()
@@ -102,6 +119,52 @@ where
         self.make_item_or_err(item)
     }
 
+    pub fn lookup_label(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+        self.expected.push(TokenType::Label);
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        match token.kind {
+            TokenKind::Label { .. } => true,
+            _ => false,
+        }
+    }
+
+    pub fn label(&mut self) -> Option<Id> {
+        self.fetch_tokens(1);
+        self.expected.push(TokenType::Label);
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return None;
+        };
+
+        match token.kind {
+            TokenKind::Label { symbol } => {
+                self.token_buffer.pop_front();
+                self.expected.clear();
+                self.last_span = token.span;
+
+                Some(Id {
+                    span: token.span,
+                    symbol,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    pub fn label_or_err(&mut self) -> Result<Id, ()> {
+        let item = self.label();
+        self.make_item_or_err(item)
+    }
+
     pub fn lookup_keyword(&mut self, offset: usize, keyword: Symbol) -> bool {
         self.fetch_tokens(offset + 1);
         self.expected.push(TokenType::Keyword(keyword));
@@ -253,6 +316,49 @@ where
         self.make_item_or_err(item)
     }
 
+    pub fn lookup_range_op(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+        self.expected.push(TokenType::RangeOp);
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        match token.kind {
+            TokenKind::Rng | TokenKind::RngInclusive => true,
+            _ => false,
+        }
+    }
+
+    pub fn range_op(&mut self) -> Option<Token> {
+        self.fetch_tokens(1);
+        self.expected.push(TokenType::RangeOp);
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return None;
+        };
+
+        match token.kind {
+            TokenKind::Rng | TokenKind::RngInclusive => {
+                self.token_buffer.pop_front();
+                self.expected.clear();
+                self.last_span = token.span;
+
+                Some(token)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn range_op_or_err(&mut self) -> Result<Token, ()> {
+        let item = self.range_op();
+        self.make_item_or_err(item)
+    }
+
     pub fn lookup_unary_op(&mut self, offset: usize) -> bool {
         self.fetch_tokens(offset + 1);
         self.expected.push(TokenType::UnaryOp);
@@ -385,6 +491,61 @@ where
         self.make_item_or_err(item)
     }
 
+    /// Doc comments are never reported as expected tokens, as they are optional everywhere.
+    pub fn lookup_doc_comment(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        matches!(token.kind, TokenKind::DocComment { .. })
+    }
+
+    pub fn doc_comment(&mut self) -> Option<Token> {
+        self.fetch_tokens(1);
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return None;
+        };
+
+        match token.kind {
+            TokenKind::DocComment { .. } => {
+                self.token_buffer.pop_front();
+                self.expected.clear();
+                self.last_span = token.span;
+
+                Some(token)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn doc_comment_or_err(&mut self) -> Result<Token, ()> {
+        let item = self.doc_comment();
+        self.make_item_or_err(item)
+    }
+
+    pub fn lookup_literal(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+        self.expected.push(TokenType::Literal);
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        match token.kind {
+            TokenKind::Literal(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn literal(&mut self) -> Option<TokenLiteral> {
         self.fetch_tokens(1);
         self.expected.push(TokenType::Literal);
@@ -412,6 +573,143 @@ where
         self.make_item_or_err(item)
     }
 
+    /// Splits a float literal like `0.1` at the front into `0`, `.` and `1`,
+    /// so that nested positional members like `t.0.1` can be parsed. Other tokens are left as is.
+    pub fn split_float_literal(&mut self) {
+        self.fetch_tokens(1);
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return;
+        };
+
+        let literal = match token.kind {
+            TokenKind::Literal(literal)
+                if literal.kind == TokenLiteralKind::Float && literal.suffix.is_none() =>
+            {
+                literal
+            }
+            _ => return,
+        };
+
+        let (integer, fraction) = match literal.content.to_str().split_once('.') {
+            Some((integer, fraction))
+                if !integer.is_empty()
+                    && !fraction.is_empty()
+                    && integer.chars().all(|char| char.is_ascii_digit())
+                    && fraction.chars().all(|char| char.is_ascii_digit()) =>
+            {
+                (integer, fraction)
+            }
+            _ => return,
+        };
+
+        let make_integer = |content: &str| {
+            TokenKind::Literal(TokenLiteral::new(
+                TokenLiteralKind::IntegerDecimal,
+                Symbol::from_str(content),
+                None,
+                content.parse().ok().map(TokenLiteralValue::Integer),
+            ))
+        };
+        let dot = token.span.low + integer.len() as u32;
+
+        self.token_buffer.pop_front();
+        self.token_buffer.push_front(Token::new(
+            Span::new(dot + 1, token.span.high),
+            make_integer(fraction),
+        ));
+        self.token_buffer
+            .push_front(Token::new(Span::new(dot, dot + 1), TokenKind::Dot));
+        self.token_buffer.push_front(Token::new(
+            Span::new(token.span.low, dot),
+            make_integer(integer),
+        ));
+    }
+
+    pub fn lookup_interpolated_string_head(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+        self.expected.push(TokenType::Literal);
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        matches!(token.kind, TokenKind::InterpolatedString { part, .. } if part.is_start())
+    }
+
+    /// Returns the first part of an interpolated string, which may also be the last one.
+    pub fn interpolated_string_head(&mut self) -> Option<Token> {
+        self.fetch_tokens(1);
+        self.expected.push(TokenType::Literal);
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return None;
+        };
+
+        match token.kind {
+            TokenKind::InterpolatedString { part, .. } if part.is_start() => {
+                self.token_buffer.pop_front();
+                self.expected.clear();
+                self.last_span = token.span;
+
+                Some(token)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn interpolated_string_head_or_err(&mut self) -> Result<Token, ()> {
+        let item = self.interpolated_string_head();
+        self.make_item_or_err(item)
+    }
+
+    pub fn lookup_interpolated_string_continuation(&mut self, offset: usize) -> bool {
+        self.fetch_tokens(offset + 1);
+        self.expected.push(TokenType::Token(TokenKind::CloseBrace));
+
+        let token = if let Some(token) = self.token_buffer.get(offset) {
+            token
+        } else {
+            return false;
+        };
+
+        matches!(token.kind, TokenKind::InterpolatedString { part, .. } if !part.is_start())
+    }
+
+    /// Returns the part of an interpolated string that follows an interpolation, which begins with `}`.
+    pub fn interpolated_string_continuation(&mut self) -> Option<Token> {
+        self.fetch_tokens(1);
+        self.expected.push(TokenType::Token(TokenKind::CloseBrace));
+
+        let token = if let Some(token) = self.token_buffer.get(0).cloned() {
+            token
+        } else {
+            return None;
+        };
+
+        match token.kind {
+            TokenKind::InterpolatedString { part, .. } if !part.is_start() => {
+                self.token_buffer.pop_front();
+                self.expected.clear();
+                self.last_span = token.span;
+
+                Some(token)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn interpolated_string_continuation_or_err(&mut self) -> Result<Token, ()> {
+        let item = self.interpolated_string_continuation();
+        self.make_item_or_err(item)
+    }
+
     pub fn skip_tokens(&mut self, mut f: impl FnMut(&Token) -> bool) {
         self.expected.clear();
 
@@ -425,10 +723,20 @@ where
         }
     }
 
+    pub fn skip_token(&mut self) {
+        self.expected.clear();
+
+        if let Some(token) = self.next() {
+            self.token_buffer.pop_front();
+            self.last_span = token.span;
+        }
+    }
+
     fn fill_buffer(&mut self) -> bool {
         let token = if let Some(token) = self.token_stream.next() {
             match token.kind {
-                TokenKind::Whitespace | TokenKind::Comment => {
+                // unknown characters are already reported by the lexer
+                TokenKind::Whitespace | TokenKind::Comment | TokenKind::Unknown { .. } => {
                     self.last_span = token.span;
                     return true;
                 }
@@ -458,7 +766,7 @@ where
         }
     }
 
-    fn current_pos(&mut self) -> Pos {
+    pub fn current_pos(&mut self) -> Pos {
         self.fetch_tokens(1);
 
         match self.token_buffer.front() {
@@ -9,7 +9,9 @@ pub enum TokenType {
     AssignmentOp,
     UnaryOp,
     BinaryOp,
+    RangeOp,
     Identifier,
+    Label,
     Path,
     Typename,
     Literal,
@@ -24,7 +26,9 @@ impl Display for TokenType {
             TokenType::AssignmentOp => write!(f, "an assignment operator"),
             TokenType::UnaryOp => write!(f, "an unary operator"),
             TokenType::BinaryOp => write!(f, "a binary operator"),
+            TokenType::RangeOp => write!(f, "a range operator"),
             TokenType::Identifier => write!(f, "identifier"),
+            TokenType::Label => write!(f, "label"),
             TokenType::Path => write!(f, "path"),
             TokenType::Typename => write!(f, "typename"),
             TokenType::Literal => write!(f, "a literal"),
@@ -0,0 +1,225 @@
+use crate::{
+    low_token_iter, parse_module, token_iter, token_iter_in_span, ASTModule, ASTModuleItem,
+    ASTModuleItemKind, LowTokenInterpolatedStringPart, LowTokenKind, LowTokenLiteralKind,
+    NodeIdAllocator, ShiftSpan,
+};
+use exc_diagnostic::{DiagnosticsLevel, DiagnosticsSender};
+use exc_span::{Pos, SourceFile, Span};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+/// A replacement of a range of a source file.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TextEdit {
+    /// the replaced range, in the coordinates of the file before the edit
+    pub span: Span,
+    pub text: String,
+}
+
+impl TextEdit {
+    pub fn new(span: Span, text: impl Into<String>) -> Self {
+        Self {
+            span,
+            text: text.into(),
+        }
+    }
+
+    /// Returns the content of the given file after the edit.
+    pub fn apply(&self, file: &SourceFile) -> String {
+        let base = file.span().low;
+        let low = (self.span.low - base).get() as usize;
+        let high = (self.span.high - base).get() as usize;
+        let content = file.content();
+        format!("{}{}{}", &content[..low], self.text, &content[high..])
+    }
+}
+
+/// Parses the edited file again, reusing the previous AST as much as possible.
+///
+/// Only the smallest module item that encloses the edit is relexed and reparsed;
+/// all the other items are carried over with their spans moved into `new_file`, so their node ids stay the same.
+/// If there's no such item, the edit leaves the item unbalanced (e.g. an unclosed brace or an unterminated literal)
+/// or the reparsed item has errors, the whole file is parsed again instead.
+///
+/// The `diagnostics` must be created for `new_file`. Only the diagnostics of the reparsed region are reported,
+/// unless the whole file is parsed again.
+pub fn reparse_module(
+    old_file: &SourceFile,
+    old_ast: &ASTModule,
+    edit: &TextEdit,
+    new_file: &SourceFile,
+    id_allocator: &mut NodeIdAllocator,
+    diagnostics: &DiagnosticsSender,
+) -> ASTModule {
+    let old_base = old_file.span().low;
+    let new_base = new_file.span().low;
+    let inserted = edit.text.len() as u32;
+    let removed = edit.span.len();
+    let shift = move |pos: Pos| {
+        if pos <= edit.span.low {
+            pos - old_base + new_base
+        } else if pos < edit.span.high {
+            // the items around the edit are reparsed, so this is not expected;
+            // clamp to the end of the inserted text rather than moving before the edit
+            edit.span.low - old_base + new_base + inserted
+        } else {
+            pos - old_base + new_base + inserted - removed
+        }
+    };
+
+    let mut reparser = Reparser {
+        edit,
+        new_file,
+        shift: &shift,
+        id_allocator,
+        diagnostics,
+    };
+
+    match reparser.reparse_items(&old_ast.items) {
+        Some(items) => {
+            let mut span = old_ast.span;
+            span.shift_span(&shift);
+            ASTModule {
+                id: old_ast.id,
+                span,
+                items,
+            }
+        }
+        None => parse_module(
+            token_iter(new_file, diagnostics),
+            reparser.id_allocator,
+            diagnostics,
+        ),
+    }
+}
+
+struct Reparser<'a> {
+    edit: &'a TextEdit,
+    new_file: &'a SourceFile,
+    shift: &'a dyn Fn(Pos) -> Pos,
+    id_allocator: &'a mut NodeIdAllocator,
+    diagnostics: &'a DiagnosticsSender,
+}
+
+impl<'a> Reparser<'a> {
+    fn reparse_items(&mut self, items: &[ASTModuleItem]) -> Option<Vec<ASTModuleItem>> {
+        let index = items.iter().position(|item| {
+            item.span.low < self.edit.span.low && self.edit.span.high < item.span.high
+        })?;
+        let reparsed = self.reparse_item(&items[index])?;
+
+        let mut result = Vec::with_capacity(items.len() - 1 + reparsed.len());
+        result.extend(items[..index].iter().map(|item| self.shift_item(item)));
+        result.extend(reparsed);
+        result.extend(items[index + 1..].iter().map(|item| self.shift_item(item)));
+        Some(result)
+    }
+
+    fn reparse_item(&mut self, item: &ASTModuleItem) -> Option<Vec<ASTModuleItem>> {
+        // try the items in the module first, the smaller the better
+        if let ASTModuleItemKind::ModuleDef(module_def) = &item.kind {
+            if module_def.token_brace_open.span.high <= self.edit.span.low
+                && self.edit.span.high <= module_def.token_brace_close.span.low
+            {
+                if let Some(items) = self.reparse_items(&module_def.items) {
+                    let mut item = item.clone();
+
+                    // the old items straddle the edit; they're replaced anyway
+                    if let ASTModuleItemKind::ModuleDef(module_def) = &mut item.kind {
+                        Arc::make_mut(module_def).items = Vec::new();
+                    }
+
+                    item.shift_span(self.shift);
+
+                    if let ASTModuleItemKind::ModuleDef(module_def) = &mut item.kind {
+                        Arc::make_mut(module_def).items = items;
+                    }
+
+                    return Some(vec![item]);
+                }
+            }
+        }
+
+        let span = Span::new((self.shift)(item.span.low), (self.shift)(item.span.high));
+
+        if !is_self_contained(self.new_file.slice(span)) {
+            return None;
+        }
+
+        // the diagnostics are held back until it's sure that the region can be parsed on its own
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+        let region_diagnostics = DiagnosticsSender::new(self.diagnostics.file().clone(), sender);
+        let module = parse_module(
+            token_iter_in_span(self.new_file, span, &region_diagnostics),
+            self.id_allocator,
+            &region_diagnostics,
+        );
+
+        drop(region_diagnostics);
+
+        let mut collected = Vec::new();
+
+        while let Ok(diagnostic) = receiver.try_recv() {
+            // a broken item may end elsewhere than the region, e.g. past it or at an earlier `}`;
+            // only the whole file can tell where it ends
+            if diagnostic.level == DiagnosticsLevel::Error {
+                return None;
+            }
+
+            collected.push(diagnostic);
+        }
+
+        for diagnostic in collected {
+            self.diagnostics.send(diagnostic);
+        }
+
+        Some(module.items)
+    }
+
+    fn shift_item(&self, item: &ASTModuleItem) -> ASTModuleItem {
+        let mut item = item.clone();
+        item.shift_span(self.shift);
+        item
+    }
+}
+
+/// Checks that the region can be lexed without its surroundings;
+/// it must not leave any literal, comment or brace open, nor close one opened outside of it.
+fn is_self_contained(content: &str) -> bool {
+    let mut braces = 0usize;
+    let mut interpolations = 0usize;
+
+    for token in low_token_iter(content) {
+        match token.kind {
+            LowTokenKind::BlockComment { terminated: false }
+            | LowTokenKind::InterpolatedString {
+                terminated: false, ..
+            }
+            | LowTokenKind::Literal {
+                kind:
+                    LowTokenLiteralKind::Character { terminated: false }
+                    | LowTokenLiteralKind::String { terminated: false }
+                    | LowTokenLiteralKind::RawString {
+                        terminated: false, ..
+                    }
+                    | LowTokenLiteralKind::MultiLineString { terminated: false },
+            } => return false,
+            LowTokenKind::OpenBrace => braces += 1,
+            LowTokenKind::CloseBrace => match braces.checked_sub(1) {
+                Some(depth) => braces = depth,
+                None => return false,
+            },
+            LowTokenKind::InterpolatedString { part, .. } => match part {
+                LowTokenInterpolatedStringPart::Whole | LowTokenInterpolatedStringPart::Middle => {}
+                LowTokenInterpolatedStringPart::Head => interpolations += 1,
+                LowTokenInterpolatedStringPart::Tail => match interpolations.checked_sub(1) {
+                    Some(depth) => interpolations = depth,
+                    None => return false,
+                },
+            },
+            _ => {}
+        }
+    }
+
+    braces == 0 && interpolations == 0
+}
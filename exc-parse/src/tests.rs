@@ -1,14 +1,26 @@
+mod attribute;
+mod comment;
+mod expr;
 mod fuzz;
+mod lexer_label;
+mod lexer_literal;
+mod lexer_unknown;
+mod lossless;
 mod module_item_alias;
+mod module_item_const;
+mod module_item_enum;
 mod module_item_extern;
 mod module_item_fn;
 mod module_item_impl;
 mod module_item_interface;
 mod module_item_struct;
 mod module_item_use;
+mod reparse;
+mod stmt;
+mod visibility;
 
-use crate::{parse_module, token_iter, ASTModule, NodeIdAllocator};
-use exc_diagnostic::DiagnosticsSender;
+use crate::{parse_module, token_iter, ASTModule, NodeIdAllocator, Token, TokenKind};
+use exc_diagnostic::{Diagnostics, DiagnosticsSender};
 use exc_span::SourceMap;
 use std::path::PathBuf;
 use tokio::sync::mpsc;
@@ -18,7 +30,7 @@ async fn parse_module_for_test(content: impl AsRef<str>) -> ASTModule {
     let file = source_map.add_source_file(content.as_ref(), "test.exc", None::<PathBuf>);
     let (sender, mut receiver) = mpsc::unbounded_channel();
     let diagnostics = DiagnosticsSender::new(file.clone(), sender);
-    let token_stream = token_iter(&file);
+    let token_stream = token_iter(&file, &diagnostics);
     let mut id_allocator = NodeIdAllocator::new();
 
     tokio::spawn(async move {
@@ -29,3 +41,45 @@ async fn parse_module_for_test(content: impl AsRef<str>) -> ASTModule {
 
     parse_module(token_stream, &mut id_allocator, &diagnostics)
 }
+
+fn parse_module_with_diagnostics_for_test(
+    content: impl AsRef<str>,
+) -> (ASTModule, Vec<Diagnostics>) {
+    let mut source_map = SourceMap::new();
+    let file = source_map.add_source_file(content.as_ref(), "test.exc", None::<PathBuf>);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let diagnostics = DiagnosticsSender::new(file.clone(), sender);
+    let token_stream = token_iter(&file, &diagnostics);
+    let mut id_allocator = NodeIdAllocator::new();
+    let ast = parse_module(token_stream, &mut id_allocator, &diagnostics);
+
+    drop(diagnostics);
+
+    let mut collected = Vec::new();
+
+    while let Ok(diagnostic) = receiver.try_recv() {
+        collected.push(diagnostic);
+    }
+
+    (ast, collected)
+}
+
+fn lex_for_test(content: impl AsRef<str>) -> (Vec<Token>, Vec<Diagnostics>) {
+    let mut source_map = SourceMap::new();
+    let file = source_map.add_source_file(content.as_ref(), "test.exc", None::<PathBuf>);
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let diagnostics = DiagnosticsSender::new(file.clone(), sender);
+    let tokens = token_iter(&file, &diagnostics)
+        .filter(|token| !matches!(token.kind, TokenKind::Whitespace | TokenKind::Comment))
+        .collect();
+
+    drop(diagnostics);
+
+    let mut collected = Vec::new();
+
+    while let Ok(diagnostic) = receiver.try_recv() {
+        collected.push(diagnostic);
+    }
+
+    (tokens, collected)
+}
@@ -0,0 +1,140 @@
+use crate::{
+    tests::{parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTExternBlockItemKind, ASTModuleItemKind,
+};
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn test_attribute() {
+    const CONTENT: &str = "
+    ## Adds two numbers.
+    @inline
+    @deprecated(\"use `sum` instead\", \"0.2\")
+    fn add(a: i32, b: i32) -> i32 { a + b }
+
+    struct Point {
+        @deprecated
+        x: i32,
+        y: i32,
+    }
+
+    extern {
+        @link_name(\"puts\")
+        prototype print(s: string);
+    }
+
+    impl Point {
+        @test()
+        fn new() {}
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 4);
+
+    let attributes = &ast.items[0].attributes;
+    assert!(ast.items[0].doc_comment.is_some());
+    assert_eq!(attributes.len(), 2);
+    assert_eq!(attributes[0].identifier.symbol.to_str(), "inline");
+    assert!(attributes[0].args.is_none());
+    assert_eq!(attributes[1].identifier.symbol.to_str(), "deprecated");
+    assert_eq!(attributes[1].args.as_ref().unwrap().args.items.len(), 2);
+
+    match &ast.items[1].kind {
+        ASTModuleItemKind::StructDef(ast) => {
+            let fields = ast
+                .fields
+                .items
+                .iter()
+                .map(|item| item.clone().into_item())
+                .collect::<Vec<_>>();
+            assert_eq!(fields[0].attributes.len(), 1);
+            assert!(fields[1].attributes.is_empty());
+        }
+        _ => panic!("expected struct module item"),
+    }
+
+    match &ast.items[2].kind {
+        ASTModuleItemKind::ExternBlock(ast) => {
+            assert_eq!(
+                ast.items[0].attributes[0].identifier.symbol.to_str(),
+                "link_name"
+            );
+            assert!(matches!(
+                ast.items[0].kind,
+                ASTExternBlockItemKind::PrototypeDef(_)
+            ));
+        }
+        _ => panic!("expected extern module item"),
+    }
+
+    match &ast.items[3].kind {
+        ASTModuleItemKind::ImplBlock(ast) => {
+            let args = ast.items[0].attributes[0].args.as_ref().unwrap();
+            assert!(args.args.items.is_empty());
+        }
+        _ => panic!("expected impl module item"),
+    }
+}
+
+#[tokio::test]
+async fn test_attribute_recovery() {
+    const CONTENT: &str = "
+    alias broken = ;
+    @inline
+    fn add() {}
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 1);
+    assert_eq!(ast.items[0].attributes.len(), 1);
+    assert!(matches!(ast.items[0].kind, ASTModuleItemKind::FnDef(_)));
+}
+
+#[test]
+fn test_attribute_not_allowed() {
+    const CONTENT: &str = "
+    fn f() {
+      @inline
+      let a = 1;
+      let b = 1 @ 2;
+    }
+
+    interface I {
+      @inline
+      fn g();
+    }
+";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    assert_eq!(ast.items.len(), 2);
+
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::ATTRIBUTE_NOT_ALLOWED,
+            error_codes::UNEXPECTED_TOKEN,
+            error_codes::UNEXPECTED_TOKEN,
+            error_codes::ATTRIBUTE_NOT_ALLOWED,
+        ]
+    );
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::FnDef(ast) => {
+            // `let a = 1;` and the trailing `2;`
+            assert_eq!(ast.stmt_block.stmts.len(), 2);
+        }
+        _ => panic!("expected fn module item"),
+    }
+
+    match &ast.items[1].kind {
+        ASTModuleItemKind::InterfaceDef(ast) => {
+            assert_eq!(ast.items.len(), 1);
+        }
+        _ => panic!("expected interface module item"),
+    }
+}
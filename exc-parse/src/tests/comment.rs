@@ -0,0 +1,176 @@
+use crate::{
+    tests::{lex_for_test, parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTExternBlockItemKind, ASTModuleItemKind, TokenKind,
+};
+use exc_diagnostic::{error_codes, warning_codes};
+
+#[test]
+fn test_comment_block() {
+    const CONTENT: &'static str = "
+    a #[ single line ]# b
+    #[
+        multi
+        #[ nested #[ twice ]# ]#
+        line
+    ]#
+    c
+    ### not a doc comment
+    d
+";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    let symbols = tokens
+        .iter()
+        .map(|token| match token.kind {
+            TokenKind::Id { symbol } => symbol.to_str(),
+            _ => panic!("expected identifier token"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(symbols, vec!["a", "b", "c", "d"]);
+}
+
+#[test]
+fn test_comment_block_unterminated() {
+    const CONTENT: &'static str = "a #[ #[ nested ]# b";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 1);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_BLOCK_COMMENT);
+
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 2);
+    assert_eq!(origin.span.high.get(), 4);
+}
+
+#[tokio::test]
+async fn test_comment_doc() {
+    const CONTENT: &'static str = "
+    ## A point.
+    ##
+    ## Has two coordinates.
+    pub struct Point {
+        ## The x coordinate.
+        x: int,
+        y: int,
+    }
+
+    # not a doc comment
+    fn foo() {}
+
+    extern {
+        ## Prints a string.
+        prototype print(s: string);
+    }
+
+    interface Foo {
+        ## Does foo.
+        fn foo();
+    }
+
+    impl Point {
+        ## Creates a point.
+        fn new() {}
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 5);
+
+    let doc = ast.items[0].doc_comment.as_ref().unwrap();
+    assert_eq!(doc.lines.len(), 3);
+    assert_eq!(doc.content(), "A point.\n\nHas two coordinates.");
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::StructDef(ast) => {
+            let fields = ast
+                .fields
+                .items
+                .iter()
+                .map(|item| item.clone().into_item())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                fields[0].doc_comment.as_ref().unwrap().content(),
+                "The x coordinate."
+            );
+            assert!(fields[1].doc_comment.is_none());
+        }
+        _ => panic!("expected struct module item"),
+    }
+
+    assert!(ast.items[1].doc_comment.is_none());
+
+    match &ast.items[2].kind {
+        ASTModuleItemKind::ExternBlock(ast) => {
+            assert_eq!(
+                ast.items[0].doc_comment.as_ref().unwrap().content(),
+                "Prints a string."
+            );
+            assert!(matches!(
+                ast.items[0].kind,
+                ASTExternBlockItemKind::PrototypeDef(_)
+            ));
+        }
+        _ => panic!("expected extern module item"),
+    }
+
+    match &ast.items[3].kind {
+        ASTModuleItemKind::InterfaceDef(ast) => {
+            assert_eq!(
+                ast.items[0].doc_comment.as_ref().unwrap().content(),
+                "Does foo."
+            );
+        }
+        _ => panic!("expected interface module item"),
+    }
+
+    match &ast.items[4].kind {
+        ASTModuleItemKind::ImplBlock(ast) => {
+            assert_eq!(
+                ast.items[0].doc_comment.as_ref().unwrap().content(),
+                "Creates a point."
+            );
+        }
+        _ => panic!("expected impl module item"),
+    }
+}
+
+#[test]
+fn test_comment_doc_unattached() {
+    const CONTENT: &'static str = "fn f() {\n ## explain\n let a = 1;\n}";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::DOC_COMMENT_NOT_ATTACHED);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::FnDef(ast) => {
+            assert_eq!(ast.stmt_block.stmts.len(), 1);
+        }
+        _ => panic!("expected fn module item"),
+    }
+}
+
+#[test]
+fn test_comment_text_direction_codepoint() {
+    const CONTENT: &'static str =
+        "a # \u{202E}} \u{2066}if admin {\u{2069} \u{2066}\n#[ \u{202D} ]#\n## plain\nb";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(diagnostics.len(), 2);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, warning_codes::TEXT_DIRECTION_CODEPOINT);
+    }
+
+    // one hint per codepoint, and a closing note
+    assert_eq!(diagnostics[0].sub_diagnostics.len(), 5);
+    assert_eq!(diagnostics[1].sub_diagnostics.len(), 2);
+
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 4);
+    assert_eq!(origin.span.high.get(), 7);
+}
@@ -0,0 +1,416 @@
+use crate::{
+    tests::parse_module_for_test, ASTExpr, ASTExprKind, ASTExprMatchArmBody, ASTExprMemberKind,
+    ASTExprRangeOperatorKind, ASTModuleItemKind, ASTPatternKind, ASTStmtKind, PunctuatedItem,
+};
+
+/// Collects the initializer expressions of the `let` statements in the first function.
+async fn let_exprs_for_test(content: &str) -> Vec<ASTExpr> {
+    let ast = parse_module_for_test(content).await;
+    let stmts = match &ast.items[0].kind {
+        ASTModuleItemKind::FnDef(ast) => &ast.stmt_block.stmts,
+        _ => panic!("expected fn module item"),
+    };
+
+    stmts
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ASTStmtKind::Let(ast) => ast.expr.as_ref().unwrap().expr.clone(),
+            _ => panic!("expected let statement"),
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_expr_interpolated_string() {
+    const CONTENT: &'static str = r#"
+    fn foo() {
+        let a = f"no interpolation";
+        let b = f"{x}";
+        let c = f"x = {x}, y = {foo(y, S { z: 1 }).w + 1}!";
+        let d = f"outer {f"inner {x}"}";
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 4);
+
+    let interpolation_counts = exprs
+        .iter()
+        .map(|expr| match &expr.kind {
+            ASTExprKind::InterpolatedString(ast) => ast.interpolations.len(),
+            _ => panic!("expected interpolated string expression"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(interpolation_counts, vec![0, 1, 2, 1]);
+
+    match &exprs[2].kind {
+        ASTExprKind::InterpolatedString(ast) => {
+            assert!(matches!(
+                ast.interpolations[0].expr.kind,
+                ASTExprKind::Path(_)
+            ));
+            assert!(matches!(
+                ast.interpolations[1].expr.kind,
+                ASTExprKind::Binary(_)
+            ));
+        }
+        _ => unreachable!(),
+    }
+
+    match &exprs[3].kind {
+        ASTExprKind::InterpolatedString(ast) => {
+            assert!(matches!(
+                ast.interpolations[0].expr.kind,
+                ASTExprKind::InterpolatedString(_)
+            ));
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_interpolated_string_unterminated_interpolation() {
+    const CONTENT: &'static str = r#"
+    fn foo() {
+        let a = f"x = {x;
+    }
+
+    fn bar() {}
+"#;
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 2);
+
+    match &ast.items[1].kind {
+        ASTModuleItemKind::FnDef(ast) => assert_eq!(ast.identifier.symbol.to_str(), "bar"),
+        _ => panic!("expected fn module item"),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_array_and_index() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = [];
+        let b = [1, 2, 3,];
+        let c = [x + 1; N];
+        let d = a[0].b[i + 1](2)[3];
+        let e = -a[0];
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 5);
+
+    match &exprs[0].kind {
+        ASTExprKind::ArrayLiteral(ast) => assert_eq!(ast.elements.items.len(), 0),
+        _ => panic!("expected array literal expression"),
+    }
+
+    match &exprs[1].kind {
+        ASTExprKind::ArrayLiteral(ast) => assert_eq!(ast.elements.items.len(), 3),
+        _ => panic!("expected array literal expression"),
+    }
+
+    match &exprs[2].kind {
+        ASTExprKind::ArrayRepeat(ast) => {
+            assert!(matches!(ast.expr.kind, ASTExprKind::Binary(_)));
+            assert!(matches!(ast.count.kind, ASTExprKind::Path(_)));
+        }
+        _ => panic!("expected array repeat expression"),
+    }
+
+    // postfix operators are applied from left to right
+    match &exprs[3].kind {
+        ASTExprKind::Index(ast) => match &ast.expr.kind {
+            ASTExprKind::Call(ast) => match &ast.callee.expr.kind {
+                ASTExprKind::Index(ast) => {
+                    assert!(matches!(ast.expr.kind, ASTExprKind::Member(_)));
+                    assert!(matches!(ast.index.kind, ASTExprKind::Binary(_)));
+                }
+                _ => panic!("expected index expression"),
+            },
+            _ => panic!("expected call expression"),
+        },
+        _ => panic!("expected index expression"),
+    }
+
+    match &exprs[4].kind {
+        ASTExprKind::Unary(ast) => {
+            assert!(matches!(ast.operand_lhs.kind, ASTExprKind::Index(_)))
+        }
+        _ => panic!("expected unary expression"),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_range() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = 0..10;
+        let b = x + 1..=y * 2;
+        let c = ..n;
+        let d = n..;
+        let e = ..;
+        let f = values[1..len - 1];
+        let g = values[..];
+        let h = foo(a.., ..=b);
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 8);
+
+    let ranges = exprs[..5]
+        .iter()
+        .map(|expr| match &expr.kind {
+            ASTExprKind::Range(ast) => (
+                ast.start.is_some(),
+                matches!(ast.operator.kind, ASTExprRangeOperatorKind::Inclusive),
+                ast.end.is_some(),
+            ),
+            _ => panic!("expected range expression"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        vec![
+            (true, false, true),
+            (true, true, true),
+            (false, false, true),
+            (true, false, false),
+            (false, false, false),
+        ]
+    );
+
+    // ranges have the lowest precedence
+    match &exprs[1].kind {
+        ASTExprKind::Range(ast) => {
+            assert!(matches!(
+                ast.start.as_ref().unwrap().kind,
+                ASTExprKind::Binary(_)
+            ));
+            assert!(matches!(
+                ast.end.as_ref().unwrap().kind,
+                ASTExprKind::Binary(_)
+            ));
+        }
+        _ => unreachable!(),
+    }
+
+    for expr in &exprs[5..7] {
+        match &expr.kind {
+            ASTExprKind::Index(ast) => assert!(matches!(ast.index.kind, ASTExprKind::Range(_))),
+            _ => panic!("expected index expression"),
+        }
+    }
+
+    match &exprs[7].kind {
+        ASTExprKind::Call(ast) => {
+            assert_eq!(ast.args.items.len(), 2);
+            assert!(ast
+                .args
+                .items
+                .iter()
+                .all(|arg| matches!(arg.clone().into_item().kind, ASTExprKind::Range(_))));
+        }
+        _ => panic!("expected call expression"),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_match() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = match x {
+            _ => 0,
+            0 => 1,
+            -1 => 2,
+            "text" => 3,
+            1..10 => 4,
+            10..=20 => 5,
+            ..=-5 => 6,
+            30.. => 7,
+            value => value,
+            Shape::Empty => 8,
+            Shape::Rect { w: 0, h: height } => height,
+            Shape::Circle(radius, _) => radius,
+            Point { x: _, y: Point { x: 1, y: _ } } => { print(1); },
+        };
+        let b = match s {} + 1;
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 2);
+
+    let ast = match &exprs[0].kind {
+        ASTExprKind::Match(ast) => ast,
+        _ => panic!("expected match expression"),
+    };
+    let arms = ast
+        .arms
+        .items
+        .iter()
+        .map(|arm| match arm {
+            PunctuatedItem::Punctuated { item, .. } => item,
+            PunctuatedItem::NotPunctuated { item } => item,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(arms.len(), 13);
+
+    let kinds = arms
+        .iter()
+        .map(|arm| match &arm.pattern.kind {
+            ASTPatternKind::Wildcard(_) => "wildcard",
+            ASTPatternKind::Binding(_) => "binding",
+            ASTPatternKind::Literal(_) => "literal",
+            ASTPatternKind::Range(_) => "range",
+            ASTPatternKind::Path(_) => "path",
+            ASTPatternKind::Struct(_) => "struct",
+            ASTPatternKind::TupleStruct(_) => "tuple struct",
+            ASTPatternKind::Tuple(_) => "tuple",
+            ASTPatternKind::Array(_) => "array",
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        kinds,
+        vec![
+            "wildcard",
+            "literal",
+            "literal",
+            "literal",
+            "range",
+            "range",
+            "range",
+            "range",
+            "binding",
+            "path",
+            "struct",
+            "tuple struct",
+            "struct",
+        ]
+    );
+
+    match &arms[6].pattern.kind {
+        ASTPatternKind::Range(ast) => {
+            assert!(ast.start.is_none());
+            assert!(ast.end.as_ref().unwrap().token_minus.is_some());
+        }
+        _ => unreachable!(),
+    }
+    match &arms[7].pattern.kind {
+        ASTPatternKind::Range(ast) => assert!(ast.end.is_none()),
+        _ => unreachable!(),
+    }
+    match &arms[11].pattern.kind {
+        ASTPatternKind::TupleStruct(ast) => assert_eq!(ast.fields.items.len(), 2),
+        _ => unreachable!(),
+    }
+    assert!(matches!(arms[12].body, ASTExprMatchArmBody::Block(_)));
+
+    // `{` after the scrutinee begins the arms, not a struct literal
+    match &exprs[1].kind {
+        ASTExprKind::Binary(ast) => match &ast.operand_lhs.kind {
+            ASTExprKind::Match(ast) => {
+                assert!(matches!(ast.expr.kind, ASTExprKind::Path(_)));
+                assert!(ast.arms.items.is_empty());
+            }
+            _ => panic!("expected match expression"),
+        },
+        _ => panic!("expected binary expression"),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_closure() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = fn () {};
+        let b = fn (x: i32, Point { y }: Point) -> i32 { return x + y; };
+        let c = fn (x: i32) -> fn (i32) -> i32 { return fn (y: i32) -> i32 { return x + y; }; }(1)(2);
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 3);
+
+    match &exprs[0].kind {
+        ASTExprKind::Closure(ast) => {
+            assert!(ast.params.items.is_empty());
+            assert!(ast.result.is_none());
+            assert!(ast.stmt_block.stmts.is_empty());
+        }
+        _ => panic!("expected closure expression"),
+    }
+    match &exprs[1].kind {
+        ASTExprKind::Closure(ast) => {
+            assert_eq!(ast.params.items.len(), 2);
+            assert!(ast.result.is_some());
+        }
+        _ => panic!("expected closure expression"),
+    }
+
+    // a closure can be called in place
+    match &exprs[2].kind {
+        ASTExprKind::Call(ast) => match &ast.callee.expr.kind {
+            ASTExprKind::Call(ast) => {
+                assert!(matches!(ast.callee.expr.kind, ASTExprKind::Closure(_)))
+            }
+            _ => panic!("expected call expression"),
+        },
+        _ => panic!("expected call expression"),
+    }
+}
+
+#[tokio::test]
+async fn test_expr_tuple_and_member() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = ();
+        let b = (x);
+        let c = (x,);
+        let d = (x, y + 1, (z, w));
+        let e = t.0;
+        let f = t.0.1;
+        let g = t.1.field.2;
+    }
+"#;
+
+    let exprs = let_exprs_for_test(CONTENT).await;
+    assert_eq!(exprs.len(), 7);
+
+    let element_counts = exprs[..4]
+        .iter()
+        .map(|expr| match &expr.kind {
+            ASTExprKind::Tuple(ast) => Some(ast.elements.items.len()),
+            ASTExprKind::Paren(_) => None,
+            _ => panic!("expected tuple or parenthesized expression"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(element_counts, vec![Some(0), None, Some(1), Some(3)]);
+
+    // collects the members from the outermost one
+    let members = exprs[4..]
+        .iter()
+        .map(|expr| {
+            let mut members = Vec::new();
+            let mut expr = expr;
+
+            while let ASTExprKind::Member(ast) = &expr.kind {
+                members.push(match &ast.member {
+                    ASTExprMemberKind::Named(id) => id.symbol.to_str().to_owned(),
+                    ASTExprMemberKind::Positional(ast) => ast.index.to_string(),
+                });
+                expr = &ast.expr;
+            }
+
+            assert!(matches!(expr.kind, ASTExprKind::Path(_)));
+            members
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        members,
+        vec![vec!["0"], vec!["1", "0"], vec!["2", "field", "1"]]
+    );
+}
@@ -0,0 +1,63 @@
+use crate::{tests::lex_for_test, TokenKind};
+use exc_diagnostic::error_codes;
+use exc_symbol::Symbol;
+
+#[test]
+fn test_lexer_label() {
+    const CONTENT: &'static str = "'outer: 'inner : break 'outer continue #[ comment ]# 'inner";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    let labels = tokens
+        .iter()
+        .filter_map(|token| match token.kind {
+            TokenKind::Label { symbol } => Some(symbol),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        vec![
+            Symbol::from_str("'outer"),
+            Symbol::from_str("'inner"),
+            Symbol::from_str("'outer"),
+            Symbol::from_str("'inner"),
+        ]
+    );
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn test_lexer_label_character_literal() {
+    const CONTENT: &'static str = "'a' 'ab' break 'b'";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    let kinds = tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds[..],
+        [
+            TokenKind::Literal(_),
+            TokenKind::Literal(_),
+            TokenKind::Id { .. },
+            TokenKind::Literal(_),
+        ]
+    ));
+
+    // 'ab' is still a character literal, not a label
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].code,
+        error_codes::MULTIPLE_CHARACTERS_IN_CHARACTER_LITERAL
+    );
+}
+
+#[test]
+fn test_lexer_label_unterminated_character_literal() {
+    const CONTENT: &'static str = "let c = 'a;\nlet d = 1;";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert!(tokens
+        .iter()
+        .all(|token| !matches!(token.kind, TokenKind::Label { .. })));
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_LITERAL);
+}
@@ -0,0 +1,553 @@
+use crate::{
+    tests::{lex_for_test, parse_module_for_test},
+    ASTModuleItemKind, TokenInterpolatedStringPart, TokenKind, TokenLiteral, TokenLiteralKind,
+    TokenLiteralValue, KEYWORD_FN,
+};
+use exc_diagnostic::{error_codes, warning_codes};
+use exc_symbol::Symbol;
+
+fn literal_values(content: &str) -> Vec<Option<TokenLiteralValue>> {
+    let (tokens, _) = lex_for_test(content);
+    tokens
+        .iter()
+        .map(|token| match token.kind {
+            TokenKind::Literal(literal) => literal.value,
+            _ => panic!("expected literal token"),
+        })
+        .collect()
+}
+
+#[test]
+fn test_lexer_literal_escape() {
+    const CONTENT: &'static str = r#"
+    'a' '\n' '\'' '\\' '\x41' '\u{1F600}' '\u{AC00}'
+    "plain" "tab\there" "quote\"s" "\x7F\u{0}" "\u{48}\u{49}"
+"#;
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(
+        literal_values(CONTENT),
+        vec![
+            Some(TokenLiteralValue::Character('a')),
+            Some(TokenLiteralValue::Character('\n')),
+            Some(TokenLiteralValue::Character('\'')),
+            Some(TokenLiteralValue::Character('\\')),
+            Some(TokenLiteralValue::Character('A')),
+            Some(TokenLiteralValue::Character('😀')),
+            Some(TokenLiteralValue::Character('가')),
+            Some(TokenLiteralValue::String(Symbol::from_str("plain"))),
+            Some(TokenLiteralValue::String(Symbol::from_str("tab\there"))),
+            Some(TokenLiteralValue::String(Symbol::from_str("quote\"s"))),
+            Some(TokenLiteralValue::String(Symbol::from_str("\x7f\0"))),
+            Some(TokenLiteralValue::String(Symbol::from_str("HI"))),
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_literal_escape_too_many_digits() {
+    const CONTENT: &'static str = r#""\u{FFFFFFFFF}""#;
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 1);
+
+    match tokens[0].kind {
+        TokenKind::Literal(literal) => assert_eq!(literal.value, None),
+        _ => panic!("expected literal token"),
+    }
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::INVALID_ESCAPE_SEQUENCE);
+    assert_eq!(
+        diagnostics[0].message,
+        "unicode escape must have at most 6 hex digits"
+    );
+}
+
+#[test]
+fn test_lexer_literal_escape_invalid() {
+    const CONTENT: &'static str = r#""a\qb" "\x80" "\x4" "\u{D800}" "\u{110000}" "\u{}" "\u41""#;
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 7);
+
+    for token in &tokens {
+        match token.kind {
+            TokenKind::Literal(literal) => assert_eq!(literal.value, None),
+            _ => panic!("expected literal token"),
+        }
+    }
+
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::INVALID_ESCAPE_SEQUENCE,
+            error_codes::INVALID_CODE_POINT,
+            error_codes::INVALID_ESCAPE_SEQUENCE,
+            error_codes::INVALID_CODE_POINT,
+            error_codes::INVALID_CODE_POINT,
+            error_codes::INVALID_ESCAPE_SEQUENCE,
+            error_codes::INVALID_ESCAPE_SEQUENCE,
+        ]
+    );
+
+    // `\q` is at offset 2..4 of the file
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 2);
+    assert_eq!(origin.span.high.get(), 4);
+}
+
+#[test]
+fn test_lexer_literal_character_length() {
+    const CONTENT: &'static str = "'' 'ab' '\\n\\t'";
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::EMPTY_CHARACTER_LITERAL,
+            error_codes::MULTIPLE_CHARACTERS_IN_CHARACTER_LITERAL,
+            error_codes::MULTIPLE_CHARACTERS_IN_CHARACTER_LITERAL,
+        ]
+    );
+    assert_eq!(literal_values(CONTENT), vec![None, None, None]);
+}
+
+#[test]
+fn test_lexer_literal_unterminated() {
+    const CONTENT: &'static str = "\"abc\nfn '1\n";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[0].span.high.get(), 4);
+    assert_eq!(
+        tokens[1].kind,
+        TokenKind::Id {
+            symbol: *KEYWORD_FN
+        }
+    );
+
+    assert_eq!(diagnostics.len(), 2);
+
+    for (diagnostic, (quote, cut)) in diagnostics.iter().zip([(0, 4), (8, 10)]) {
+        assert_eq!(diagnostic.code, error_codes::UNTERMINATED_LITERAL);
+
+        let origin = diagnostic.origin.as_ref().unwrap();
+        assert_eq!(origin.span.low.get(), quote);
+        assert_eq!(origin.span.high.get(), quote + 1);
+
+        let origin = diagnostic.sub_diagnostics[0].origin.as_ref().unwrap();
+        assert_eq!(origin.span.low.get(), cut);
+        assert_eq!(origin.span.high.get(), cut);
+    }
+}
+
+#[tokio::test]
+async fn test_lexer_literal_unterminated_recovery() {
+    const CONTENT: &'static str = "
+    fn foo() {
+        print(\"hello);
+    }
+
+    fn bar() {}
+    struct Baz {}
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 3);
+
+    for (item, name) in ast.items[..2].iter().zip(["foo", "bar"]) {
+        match &item.kind {
+            ASTModuleItemKind::FnDef(ast) => assert_eq!(ast.identifier.symbol.to_str(), name),
+            _ => panic!("expected fn module item"),
+        }
+    }
+
+    match &ast.items[2].kind {
+        ASTModuleItemKind::StructDef(_) => {}
+        _ => panic!("expected struct module item"),
+    }
+}
+
+#[test]
+fn test_lexer_literal_number() {
+    const CONTENT: &'static str = "
+    1_000_000 0b1010 0o17 0xFF_FF 255u8 128i8 1_u64 18446744073709551615
+    12.5 1e3 2.5E-1 1_000.000_1 2f32 3.0f64
+";
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    let values = literal_values(CONTENT);
+    assert_eq!(
+        &values[..8],
+        &[
+            Some(TokenLiteralValue::Integer(1_000_000)),
+            Some(TokenLiteralValue::Integer(0b1010)),
+            Some(TokenLiteralValue::Integer(0o17)),
+            Some(TokenLiteralValue::Integer(0xFFFF)),
+            Some(TokenLiteralValue::Integer(255)),
+            Some(TokenLiteralValue::Integer(128)),
+            Some(TokenLiteralValue::Integer(1)),
+            Some(TokenLiteralValue::Integer(u64::MAX)),
+        ]
+    );
+    assert_eq!(
+        values[8..]
+            .iter()
+            .map(|value| value.and_then(|value| value.as_f64()))
+            .collect::<Vec<_>>(),
+        vec![
+            Some(12.5),
+            Some(1e3),
+            Some(2.5e-1),
+            Some(1000.0001),
+            Some(2.0),
+            Some(3.0)
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_literal_number_invalid() {
+    const CONTENT: &'static str =
+        "0b102 0o9 256u8 129i8 18446744073709551616 1e400 1e39f32 1.0i32 0b1f32 1abc";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 10);
+    assert_eq!(literal_values(CONTENT), vec![None; 10]);
+
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::INVALID_DIGIT,
+            error_codes::INVALID_DIGIT,
+            error_codes::LITERAL_OUT_OF_RANGE,
+            error_codes::LITERAL_OUT_OF_RANGE,
+            error_codes::LITERAL_OUT_OF_RANGE,
+            error_codes::LITERAL_OUT_OF_RANGE,
+            error_codes::LITERAL_OUT_OF_RANGE,
+            error_codes::INVALID_LITERAL_SUFFIX,
+            error_codes::INVALID_LITERAL_SUFFIX,
+            error_codes::INVALID_LITERAL_SUFFIX,
+        ]
+    );
+
+    // `2` of `0b102`
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 4);
+    assert_eq!(origin.span.high.get(), 5);
+
+    assert_eq!(
+        diagnostics[9].message,
+        "invalid suffix `abc` for number literal"
+    );
+}
+
+#[test]
+fn test_lexer_literal_raw_string() {
+    const CONTENT: &'static str = r####"
+    r"C:\path\n" r#"say "hi""# r##"a "# b"## r""
+    r"multiple
+lines"
+"####;
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(
+        literal_values(CONTENT),
+        vec![
+            Some(TokenLiteralValue::String(Symbol::from_str("C:\\path\\n"))),
+            Some(TokenLiteralValue::String(Symbol::from_str("say \"hi\""))),
+            Some(TokenLiteralValue::String(Symbol::from_str("a \"# b"))),
+            Some(TokenLiteralValue::String(Symbol::from_str(""))),
+            Some(TokenLiteralValue::String(Symbol::from_str(
+                "multiple\nlines"
+            ))),
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_literal_raw_string_unterminated() {
+    const CONTENT: &'static str = "r##\"abc\"# def\nfn";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 2);
+    assert_eq!(
+        tokens[1].kind,
+        TokenKind::Id {
+            symbol: *KEYWORD_FN
+        }
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_LITERAL);
+
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 0);
+    assert_eq!(origin.span.high.get(), 4);
+
+    let origin = diagnostics[0].sub_diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 13);
+}
+
+#[test]
+fn test_lexer_literal_multi_line_string() {
+    const CONTENT: &'static str = "
+    \"\"\"
+        first
+          indented \\\"\"\"
+
+        last\\t!
+        \"\"\"
+    \"\"\"
+      closing line decides
+    \"\"\"
+    \"\"\"inline\"\"\"
+";
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(
+        literal_values(CONTENT),
+        vec![
+            Some(TokenLiteralValue::String(Symbol::from_str(
+                "first\n  indented \"\"\"\n\nlast\t!"
+            ))),
+            Some(TokenLiteralValue::String(Symbol::from_str(
+                "  closing line decides"
+            ))),
+            Some(TokenLiteralValue::String(Symbol::from_str("inline"))),
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_literal_multi_line_string_invalid() {
+    const CONTENT: &'static str = "\"\"\"\n    ok\n    \\q\n    \"\"\" \"\"\"\nabc";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 2);
+
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::INVALID_ESCAPE_SEQUENCE,
+            error_codes::UNTERMINATED_LITERAL,
+        ]
+    );
+
+    // `\q` is at offset 15..17 of the file, after the indentation is stripped
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 15);
+    assert_eq!(origin.span.high.get(), 17);
+
+    // the second literal runs to the end of the file
+    let origin = diagnostics[1].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 26);
+    assert_eq!(origin.span.high.get(), 29);
+    assert_eq!(tokens[1].span.high.get(), CONTENT.len() as u32);
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string() {
+    const CONTENT: &'static str = r#"f"plain {{braces}}" f"a{x}b{ S { y: f"{z}" } }\t}}c""#;
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    let parts = tokens
+        .iter()
+        .filter_map(|token| match token.kind {
+            TokenKind::InterpolatedString { part, literal } => Some((part, literal.value)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let string = |value: &str| Some(TokenLiteralValue::String(Symbol::from_str(value)));
+    assert_eq!(
+        parts,
+        vec![
+            (TokenInterpolatedStringPart::Whole, string("plain {braces}")),
+            (TokenInterpolatedStringPart::Head, string("a")),
+            (TokenInterpolatedStringPart::Middle, string("b")),
+            (TokenInterpolatedStringPart::Head, string("")),
+            (TokenInterpolatedStringPart::Tail, string("")),
+            (TokenInterpolatedStringPart::Tail, string("\t}c")),
+        ]
+    );
+
+    // braces of the struct literal inside the interpolation are ordinary tokens
+    let braces = tokens
+        .iter()
+        .filter(|token| matches!(token.kind, TokenKind::OpenBrace | TokenKind::CloseBrace))
+        .count();
+    assert_eq!(braces, 2);
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string_unicode_escape() {
+    const CONTENT: &'static str = r#"f"a \u{7B} b \u{7D}{x}""#;
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert!(diagnostics.is_empty());
+
+    let parts = tokens
+        .iter()
+        .filter_map(|token| match token.kind {
+            TokenKind::InterpolatedString { part, literal } => Some((part, literal.value)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let string = |value: &str| Some(TokenLiteralValue::String(Symbol::from_str(value)));
+    assert_eq!(
+        parts,
+        vec![
+            (TokenInterpolatedStringPart::Head, string("a { b }")),
+            (TokenInterpolatedStringPart::Tail, string("")),
+        ]
+    );
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string_unterminated() {
+    const CONTENT: &'static str = "f\"a{x}b\nfn";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 4);
+    assert_eq!(
+        tokens[3].kind,
+        TokenKind::Id {
+            symbol: *KEYWORD_FN
+        }
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_LITERAL);
+
+    // reported at the `}` that continues the string
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 5);
+    assert_eq!(origin.span.high.get(), 6);
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string_unterminated_interpolation() {
+    const CONTENT: &'static str = "f\"x = {x;\nfn";
+
+    // the rest of the line is a single unterminated part, without the tokens of the interpolation
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 2);
+    assert!(matches!(
+        tokens[0].kind,
+        TokenKind::InterpolatedString {
+            part: TokenInterpolatedStringPart::Whole,
+            literal: TokenLiteral {
+                kind: TokenLiteralKind::String { terminated: false },
+                ..
+            },
+        }
+    ));
+    assert_eq!(
+        tokens[1].kind,
+        TokenKind::Id {
+            symbol: *KEYWORD_FN
+        }
+    );
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_INTERPOLATION);
+
+    // reported at the `{` that opens the interpolation
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 6);
+    assert_eq!(origin.span.high.get(), 7);
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string_unterminated_nested_interpolation() {
+    const CONTENT: &'static str = "f\"a{x}b{f\"c{y\"}\nfn";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    let kinds = tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds[..],
+        [
+            TokenKind::InterpolatedString {
+                part: TokenInterpolatedStringPart::Head,
+                ..
+            },
+            TokenKind::Id { .. },
+            TokenKind::InterpolatedString {
+                part: TokenInterpolatedStringPart::Tail,
+                ..
+            },
+            TokenKind::Id { .. },
+        ]
+    ));
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNTERMINATED_INTERPOLATION);
+
+    // reported at the `{` of the outer string that is never closed
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 7);
+    assert_eq!(origin.span.high.get(), 8);
+}
+
+#[test]
+fn test_lexer_literal_interpolated_string_unmatched_closing_brace() {
+    const CONTENT: &'static str = r#"f"a } b {x} c }} d""#;
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 3);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::UNMATCHED_CLOSING_BRACE);
+
+    let origin = diagnostics[0].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 4);
+    assert_eq!(origin.span.high.get(), 5);
+}
+
+#[test]
+fn test_lexer_literal_text_direction_codepoint() {
+    const CONTENT: &'static str =
+        "\"user\u{202E} \u{2066}// admin\u{2069}\u{2066}\" 'a' r\"\u{2067}\" f\"{x}\u{2068}\" \"plain\"";
+
+    let (_, diagnostics) = lex_for_test(CONTENT);
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            warning_codes::TEXT_DIRECTION_CODEPOINT,
+            warning_codes::TEXT_DIRECTION_CODEPOINT,
+            warning_codes::TEXT_DIRECTION_CODEPOINT,
+        ]
+    );
+    assert_eq!(diagnostics[0].sub_diagnostics.len(), 5);
+}
@@ -0,0 +1,86 @@
+use crate::{
+    tests::{lex_for_test, parse_module_for_test},
+    ASTModuleItemKind, TokenKind,
+};
+use exc_diagnostic::error_codes;
+
+#[test]
+fn test_lexer_unknown_character() {
+    const CONTENT: &'static str = "a $ b ?` c";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    assert_eq!(tokens.len(), 5);
+    assert!(matches!(tokens[1].kind, TokenKind::Unknown { .. }));
+    assert!(matches!(tokens[3].kind, TokenKind::Unknown { .. }));
+
+    // `?` and `` ` `` are collapsed into a single diagnostic
+    assert_eq!(diagnostics.len(), 2);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, error_codes::UNKNOWN_CHARACTER);
+        assert!(diagnostic.sub_diagnostics.is_empty());
+    }
+
+    assert_eq!(diagnostics[1].message, "unknown characters `?``");
+
+    let origin = diagnostics[1].origin.as_ref().unwrap();
+    assert_eq!(origin.span.low.get(), 6);
+    assert_eq!(origin.span.high.get(), 8);
+}
+
+#[test]
+fn test_lexer_unknown_character_lookalike() {
+    const CONTENT: &'static str = "foo（a，b）； x\u{200B}y “s”";
+
+    let (tokens, diagnostics) = lex_for_test(CONTENT);
+    let kinds = tokens.iter().map(|token| token.kind).collect::<Vec<_>>();
+    assert!(matches!(
+        kinds.as_slice(),
+        [
+            TokenKind::Id { .. },
+            TokenKind::OpenParen,
+            TokenKind::Id { .. },
+            TokenKind::Comma,
+            TokenKind::Id { .. },
+            TokenKind::CloseParen,
+            TokenKind::Semicolon,
+            TokenKind::Id { .. },
+            TokenKind::Id { .. },
+            TokenKind::Unknown { .. },
+            TokenKind::Id { .. },
+            TokenKind::Unknown { .. },
+        ]
+    ));
+
+    assert_eq!(diagnostics.len(), 7);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, error_codes::UNKNOWN_CHARACTER);
+        assert_eq!(diagnostic.sub_diagnostics.len(), 1);
+    }
+
+    assert!(diagnostics[0].sub_diagnostics[0]
+        .message
+        .contains("consider replacing it with `(`"));
+    assert!(diagnostics[4].sub_diagnostics[0]
+        .message
+        .contains("consider removing it"));
+}
+
+#[tokio::test]
+async fn test_lexer_unknown_character_recovery() {
+    const CONTENT: &'static str = "
+    fn foo() {
+        bar()；
+        $ baz();
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 1);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::FnDef(ast) => assert_eq!(ast.stmt_block.stmts.len(), 2),
+        _ => panic!("expected fn module item"),
+    }
+}
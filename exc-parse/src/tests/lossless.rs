@@ -0,0 +1,83 @@
+use crate::{token_iter, LosslessTokenStream, TokenKind};
+use exc_diagnostic::DiagnosticsSender;
+use exc_span::SourceMap;
+use std::path::PathBuf;
+use tokio::sync::mpsc;
+
+fn lossless_for_test(content: &str) -> (LosslessTokenStream, String) {
+    let mut source_map = SourceMap::new();
+    let file = source_map.add_source_file(content, "test.exc", None::<PathBuf>);
+    let (sender, _receiver) = mpsc::unbounded_channel();
+    let diagnostics = DiagnosticsSender::new(file.clone(), sender);
+    let stream = LosslessTokenStream::new(&file, token_iter(&file, &diagnostics));
+    let source = stream.to_source(&file);
+
+    (stream, source)
+}
+
+#[test]
+fn test_lossless_round_trip() {
+    const CONTENTS: [&'static str; 12] = [
+        "",
+        "   \n\t",
+        "# only a comment",
+        "fn main() {\r\n    print(\"hello\");\r\n}\r\n",
+        "fn foo( { let = ; } }}} struct",
+        "let s = \"unterminated\nfn foo() {}",
+        "#[ unterminated #[ nested ]# block comment",
+        "a $$ b ？ c；\u{200B}d “e”",
+        "## doc\nfn foo() {} ### not doc\n#[ block\n comment ]# fn bar() {}",
+        "f\"a {x} b {f\"{y}\"}\" r#\"raw \" string\"# \"\"\"\n    multi\n    \"\"\"",
+        include_str!("./fuzz-artifacts/timeout-aa5d71c522e025bc25fca44f7f7366f2f18ecfd9"),
+        include_str!("./fuzz-artifacts/timeout-bc1f229c8ca8490dfb38478ea317f3a1f0b483f2"),
+    ];
+
+    for content in CONTENTS {
+        let (_, source) = lossless_for_test(content);
+        assert_eq!(source, content);
+    }
+}
+
+#[test]
+fn test_lossless_trivia() {
+    const CONTENT: &'static str =
+        "# header\na # same line\n  # next line\n  b #[ multi\n line ]# c\n# end";
+
+    let (stream, _) = lossless_for_test(CONTENT);
+    let slices = |trivia: &Vec<crate::Token>| {
+        trivia
+            .iter()
+            .map(|trivia| &CONTENT[trivia.span.low.get() as usize..trivia.span.high.get() as usize])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(stream.tokens.len(), 3);
+
+    assert_eq!(
+        slices(&stream.tokens[0].leading_trivia),
+        vec!["# header", "\n"]
+    );
+    assert_eq!(
+        slices(&stream.tokens[0].trailing_trivia),
+        vec![" ", "# same line", "\n"]
+    );
+
+    assert_eq!(
+        slices(&stream.tokens[1].leading_trivia),
+        vec!["  ", "# next line", "\n  "]
+    );
+    assert_eq!(
+        slices(&stream.tokens[1].trailing_trivia),
+        vec![" ", "#[ multi\n line ]#"]
+    );
+
+    // the comment ends on another line, so the whitespace after it is not trailing
+    assert_eq!(slices(&stream.tokens[2].leading_trivia), vec![" "]);
+    assert!(matches!(stream.tokens[2].token.kind, TokenKind::Id { .. }));
+    assert_eq!(slices(&stream.tokens[2].trailing_trivia), vec!["\n"]);
+    assert_eq!(slices(&stream.eof_trivia), vec!["# end"]);
+
+    let b = &stream.tokens[1];
+    assert_eq!(stream.find(b.token.span).unwrap().token.span, b.token.span);
+    assert_eq!(b.full_span().low.get(), 23);
+}
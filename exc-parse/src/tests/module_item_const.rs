@@ -0,0 +1,41 @@
+use crate::{tests::parse_module_for_test, ASTModuleItemKind};
+
+#[tokio::test]
+async fn test_module_item_const() {
+    const CONTENT: &str = "
+    const identifier: i32 = 1;
+    const identifier: path::subpath = path::subpath { field: 1 };
+    pub const identifier: [u8; 3] = [1, 2, 3];
+    pub const identifier: (i32, f32) = (1 + 2, 3.0);
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 4);
+
+    for item in ast.items.iter() {
+        match item.kind {
+            ASTModuleItemKind::ConstDef(_) => {}
+            _ => panic!("expected const module item"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_module_item_static() {
+    const CONTENT: &str = "
+    static identifier: i32 = 1;
+    static identifier: string = \"value\";
+    pub static identifier: [u8; 3] = [0; 3];
+    pub static identifier: i32 = other::identifier * 2;
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 4);
+
+    for item in ast.items.iter() {
+        match item.kind {
+            ASTModuleItemKind::StaticDef(_) => {}
+            _ => panic!("expected static module item"),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    tests::parse_module_for_test, ASTEnumDefVariantKind, ASTModuleItemKind, PunctuatedItem,
+};
+
+#[tokio::test]
+async fn test_module_item_enum() {
+    const CONTENT: &str = "
+    enum foo {}
+    enum foo<T> {}
+    enum foo<T,> {}
+    enum foo<T, U,> where T: path::to::interface, {}
+    enum foo<T, U,> where T: path::to::interface + path::to::interface, U: path::to::interface, {}
+    pub enum foo { A }
+    enum foo { A, }
+    enum foo { A, B, C }
+    enum foo<T> {
+        A,
+        B(T),
+        C { x: int },
+    }
+    enum foo<T> where T: path::to::interface {
+        ## doc comment
+        A(),
+        B(T, path::subpath<T>,),
+        C {},
+        D { x: [T; 0], y: fn(T) -> T, },
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 10);
+
+    for item in ast.items.iter() {
+        match item.kind {
+            ASTModuleItemKind::EnumDef(_) => {}
+            _ => panic!("expected enum module item"),
+        }
+    }
+
+    let ast = match &ast.items[8].kind {
+        ASTModuleItemKind::EnumDef(ast) => ast,
+        _ => unreachable!(),
+    };
+    let kinds = ast
+        .variants
+        .items
+        .iter()
+        .map(|item| match item {
+            PunctuatedItem::Punctuated { item, .. } => &item.kind,
+            PunctuatedItem::NotPunctuated { item } => &item.kind,
+        })
+        .collect::<Vec<_>>();
+
+    assert!(ast.generic_param.is_some());
+    assert!(matches!(kinds[0], ASTEnumDefVariantKind::Unit));
+    assert!(matches!(kinds[1], ASTEnumDefVariantKind::Tuple(ast) if ast.items.items.len() == 1));
+    assert!(matches!(kinds[2], ASTEnumDefVariantKind::Struct(ast) if ast.fields.items.len() == 1));
+}
@@ -1,4 +1,9 @@
-use crate::{tests::parse_module_for_test, ASTModuleItemKind};
+use crate::{
+    tests::{parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTExternBlockItemKind, ASTModuleItemKind, TokenLiteralValue,
+};
+use exc_diagnostic::error_codes;
+use exc_symbol::Symbol;
 
 #[tokio::test]
 async fn test_module_item_extern() {
@@ -256,3 +261,102 @@ async fn test_module_item_extern() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_module_item_extern_abi() {
+    const CONTENT: &str = "
+    extern \"C\" {
+        @link_name(\"puts\")
+        prototype print(s: string);
+    }
+
+    extern \"system\" { }
+
+    extern { }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 3);
+
+    let abis = ast
+        .items
+        .iter()
+        .map(|item| match &item.kind {
+            ASTModuleItemKind::ExternBlock(ast) => ast.abi.as_ref().map(|abi| abi.literal.value),
+            _ => panic!("expected extern module item"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        abis,
+        vec![
+            Some(Some(TokenLiteralValue::String(Symbol::from_str("C")))),
+            Some(Some(TokenLiteralValue::String(Symbol::from_str("system")))),
+            None,
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_module_item_extern_variadic() {
+    const CONTENT: &str = "
+    extern \"C\" {
+        prototype printf(format: string, ...) -> i32;
+        prototype any(...);
+        prototype fixed(value: i32,);
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 1);
+
+    let prototypes = match &ast.items[0].kind {
+        ASTModuleItemKind::ExternBlock(ast) => ast
+            .items
+            .iter()
+            .map(|item| match &item.kind {
+                ASTExternBlockItemKind::PrototypeDef(ast) => {
+                    (ast.params.items.len(), ast.is_variadic())
+                }
+                _ => panic!("expected prototype"),
+            })
+            .collect::<Vec<_>>(),
+        _ => panic!("expected extern module item"),
+    };
+
+    assert_eq!(prototypes, vec![(1, true), (0, true), (1, false)]);
+}
+
+#[test]
+fn test_module_item_extern_disallowed_item() {
+    const CONTENT: &str = "
+    extern {
+        static X: i32 = 1;
+        use path;
+        prototype foo();
+        pub
+    }
+";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::ITEM_NOT_ALLOWED,
+            error_codes::ITEM_NOT_ALLOWED,
+            error_codes::UNEXPECTED_TOKEN,
+        ]
+    );
+    assert_eq!(ast.items.len(), 1);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::ExternBlock(ast) => {
+            assert_eq!(ast.items.len(), 1);
+        }
+        _ => panic!("expected extern module item"),
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{tests::parse_module_for_test, ASTModuleItemKind};
+use crate::{
+    tests::{parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTModuleItemKind, ASTTyKind,
+};
+use exc_diagnostic::error_codes;
 
 #[tokio::test]
 async fn test_module_item_fn() {
@@ -64,3 +68,50 @@ async fn test_module_item_fn() {
         }
     }
 }
+
+#[tokio::test]
+async fn test_module_item_fn_tuple_ty() {
+    const CONTENT: &str = "
+    fn foo() -> () {}
+    fn foo() -> (T) {}
+    fn foo() -> (T,) {}
+    fn foo(param0: (T, [U; 2])) -> (T, (U, V), fn (T) -> (U,)) {}
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 4);
+
+    let item_counts = ast
+        .items
+        .iter()
+        .map(|item| match &item.kind {
+            ASTModuleItemKind::FnDef(ast) => match &ast.result.as_ref().unwrap().ty.kind {
+                ASTTyKind::Tuple(ast) => Some(ast.items.items.len()),
+                ASTTyKind::Paren(_) => None,
+                _ => panic!("expected tuple or parenthesized type"),
+            },
+            _ => panic!("expected fn module item"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(item_counts, vec![Some(0), None, Some(1), Some(3)]);
+}
+
+#[test]
+fn test_module_item_stray_close_brace() {
+    const CONTENT: &str = "
+    fn foo() {}
+    }
+    fn bar() {}
+    fn baz(: }
+    fn qux() {}
+";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    // the `}` ending the broken `baz` is not reported again as a stray one
+    assert_eq!(codes, vec![error_codes::UNEXPECTED_TOKEN; 3]);
+    assert_eq!(ast.items.len(), 3);
+}
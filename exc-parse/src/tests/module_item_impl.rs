@@ -1,4 +1,8 @@
-use crate::{tests::parse_module_for_test, ASTModuleItemKind};
+use crate::{
+    tests::{parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTModuleItemKind,
+};
+use exc_diagnostic::error_codes;
 
 #[tokio::test]
 async fn test_module_item_impl() {
@@ -289,3 +293,32 @@ async fn test_module_item_impl() {
         }
     }
 }
+
+#[test]
+fn test_module_item_impl_disallowed_item() {
+    const CONTENT: &str = "
+    impl path {
+        const X: i32 = 1;
+        struct S { }
+        fn method() { }
+    }
+";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    let codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        codes,
+        vec![error_codes::ITEM_NOT_ALLOWED, error_codes::ITEM_NOT_ALLOWED]
+    );
+    assert_eq!(ast.items.len(), 1);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::ImplBlock(ast) => {
+            assert_eq!(ast.items.len(), 1);
+        }
+        _ => panic!("expected impl module item"),
+    }
+}
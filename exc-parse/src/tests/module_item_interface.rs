@@ -1,4 +1,8 @@
-use crate::{tests::parse_module_for_test, ASTModuleItemKind};
+use crate::{
+    tests::{parse_module_for_test, parse_module_with_diagnostics_for_test},
+    ASTModuleItemKind,
+};
+use exc_diagnostic::error_codes;
 
 #[tokio::test]
 async fn test_module_item_interface() {
@@ -382,3 +386,25 @@ async fn test_module_item_interface() {
         }
     }
 }
+
+#[test]
+fn test_module_item_interface_disallowed_item() {
+    const CONTENT: &str = "
+    interface foo {
+        struct bar { }
+        fn baz();
+    }
+";
+
+    let (ast, diagnostics) = parse_module_with_diagnostics_for_test(CONTENT);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::ITEM_NOT_ALLOWED);
+    assert_eq!(ast.items.len(), 1);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::InterfaceDef(ast) => {
+            assert_eq!(ast.items.len(), 1);
+        }
+        _ => panic!("expected interface module item"),
+    }
+}
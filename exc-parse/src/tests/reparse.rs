@@ -0,0 +1,226 @@
+use crate::{
+    parse_module, reparse_module, token_iter, ASTModule, ASTModuleItemKind, NodeIdAllocator,
+    TextEdit,
+};
+use exc_diagnostic::{Diagnostics, DiagnosticsLevel, DiagnosticsSender};
+use exc_span::{SourceFile, SourceMap, Span};
+use std::{path::PathBuf, sync::Arc};
+use tokio::sync::mpsc;
+
+struct ReparseResult {
+    old: ASTModule,
+    incremental: ASTModule,
+    incremental_diagnostics: Vec<Diagnostics>,
+    full: ASTModule,
+    full_diagnostics: Vec<Diagnostics>,
+}
+
+fn parse_file_for_test(
+    file: &Arc<SourceFile>,
+    id_allocator: &mut NodeIdAllocator,
+) -> (ASTModule, Vec<Diagnostics>) {
+    collect_diagnostics(file, |diagnostics| {
+        parse_module(token_iter(file, diagnostics), id_allocator, diagnostics)
+    })
+}
+
+fn collect_diagnostics<T>(
+    file: &Arc<SourceFile>,
+    f: impl FnOnce(&DiagnosticsSender) -> T,
+) -> (T, Vec<Diagnostics>) {
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let diagnostics = DiagnosticsSender::new(file.clone(), sender);
+    let result = f(&diagnostics);
+
+    drop(diagnostics);
+
+    let mut collected = Vec::new();
+
+    while let Ok(diagnostic) = receiver.try_recv() {
+        collected.push(diagnostic);
+    }
+
+    (result, collected)
+}
+
+fn reparse_for_test(content: &str, replaced: &str, text: &str) -> ReparseResult {
+    let mut source_map = SourceMap::new();
+    let old_file = source_map.add_source_file(content, "test.exc", None::<PathBuf>);
+    let mut id_allocator = NodeIdAllocator::new();
+    let (old, _) = parse_file_for_test(&old_file, &mut id_allocator);
+
+    let low = old_file.span().low + content.find(replaced).unwrap() as u32;
+    let edit = TextEdit::new(Span::new(low, low + replaced.len() as u32), text);
+    let new_file = source_map.add_source_file(edit.apply(&old_file), "test.exc", None::<PathBuf>);
+
+    let (incremental, incremental_diagnostics) = collect_diagnostics(&new_file, |diagnostics| {
+        reparse_module(
+            &old_file,
+            &old,
+            &edit,
+            &new_file,
+            &mut id_allocator,
+            diagnostics,
+        )
+    });
+    let (full, full_diagnostics) = parse_file_for_test(&new_file, &mut NodeIdAllocator::new());
+
+    ReparseResult {
+        old,
+        incremental,
+        incremental_diagnostics,
+        full,
+        full_diagnostics,
+    }
+}
+
+/// Formats the module without node ids, so that the incremental result can be compared with a full parse.
+fn format_without_ids(module: &ASTModule) -> String {
+    let formatted = format!("{:?}", module);
+    let mut result = String::with_capacity(formatted.len());
+    let mut rest = formatted.as_str();
+
+    while let Some(index) = rest.find("NodeId(") {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        rest = &rest[rest.find(')').unwrap() + 1..];
+    }
+
+    result.push_str(rest);
+    result
+}
+
+fn assert_same_as_full(result: &ReparseResult) {
+    assert_eq!(
+        format_without_ids(&result.incremental),
+        format_without_ids(&result.full)
+    );
+    assert_eq!(
+        result.incremental_diagnostics.len(),
+        result.full_diagnostics.len()
+    );
+}
+
+#[test]
+fn test_reparse_fn_body() {
+    let result = reparse_for_test(
+        "fn a() { let x = 1; }\n## doc\nfn b() { let y = 2; }\nstruct S { f: i32 }\n",
+        "2",
+        "y + 200",
+    );
+
+    assert_same_as_full(&result);
+    assert_eq!(result.incremental.id, result.old.id);
+    assert_eq!(result.incremental.items.len(), 3);
+    assert_eq!(result.incremental.items[0].id, result.old.items[0].id);
+    assert_ne!(result.incremental.items[1].id, result.old.items[1].id);
+    assert_eq!(result.incremental.items[2].id, result.old.items[2].id);
+}
+
+#[test]
+fn test_reparse_nested_module_item() {
+    let result = reparse_for_test(
+        "module m {\n    fn a() {}\n    fn b() { foo(); }\n}\nfn c() {}\n",
+        "foo();",
+        "bar(1, 2);",
+    );
+
+    assert_same_as_full(&result);
+    assert_eq!(result.incremental.id, result.old.id);
+    assert_eq!(result.incremental.items[0].id, result.old.items[0].id);
+    assert_eq!(result.incremental.items[1].id, result.old.items[1].id);
+
+    let (old_items, new_items) =
+        match (&result.old.items[0].kind, &result.incremental.items[0].kind) {
+            (ASTModuleItemKind::ModuleDef(old), ASTModuleItemKind::ModuleDef(new)) => {
+                (&old.items, &new.items)
+            }
+            _ => panic!("expected module definitions"),
+        };
+
+    assert_eq!(new_items[0].id, old_items[0].id);
+    assert_ne!(new_items[1].id, old_items[1].id);
+}
+
+#[test]
+fn test_reparse_attributed_module_item() {
+    let result = reparse_for_test(
+        "@test\nmodule m {\n    fn a() {}\n    @test\n    fn b() { foo(); }\n}\n",
+        "foo();",
+        "bar(1, 2);",
+    );
+
+    assert_same_as_full(&result);
+    assert_eq!(result.incremental.items[0].id, result.old.items[0].id);
+    assert_eq!(result.incremental.items[0].attributes.len(), 1);
+}
+
+#[test]
+fn test_reparse_split_item() {
+    let result = reparse_for_test(
+        "fn a() { foo(); bar(); }\nfn b() {}\n",
+        " bar();",
+        " }\nfn c() { bar();",
+    );
+
+    assert_same_as_full(&result);
+    assert_eq!(result.incremental.id, result.old.id);
+    assert_eq!(result.incremental.items.len(), 3);
+    assert_eq!(result.incremental.items[2].id, result.old.items[1].id);
+}
+
+#[test]
+fn test_reparse_reports_warnings_in_region() {
+    let result = reparse_for_test(
+        "fn a() { let x = 1; }\nfn b() {}\n",
+        "1;",
+        "1; #[ \u{202E} ]#",
+    );
+
+    assert_same_as_full(&result);
+    assert_eq!(result.incremental.id, result.old.id);
+    assert_eq!(result.incremental_diagnostics.len(), 1);
+    assert_eq!(
+        result.incremental_diagnostics[0].level,
+        DiagnosticsLevel::Warning
+    );
+}
+
+#[test]
+fn test_reparse_falls_back_on_errors_in_region() {
+    const EDITS: [(&str, &str, &str); 2] = [
+        ("fn a() { let x = 1; }\nfn b() {}\n", "1", ""),
+        // the full parse ends `outer` at the first `}`
+        (
+            "module outer { fn helper() {} fn nested() {} } fn root() {}",
+            "fn helper() {}",
+            "fn helper( {}",
+        ),
+    ];
+
+    for (content, replaced, text) in EDITS {
+        let result = reparse_for_test(content, replaced, text);
+
+        assert_same_as_full(&result);
+        assert_ne!(result.incremental.id, result.old.id);
+        assert!(!result.incremental_diagnostics.is_empty());
+    }
+}
+
+#[test]
+fn test_reparse_falls_back_to_full_parse() {
+    const EDITS: [(&str, &str); 5] = [
+        ("1;", "1; {"),           // unclosed brace
+        ("1;", "1; }"),           // closes the enclosing item
+        ("1;", "\"1;"),           // unterminated literal
+        ("1;", "1; #[ comment"),  // unterminated block comment
+        ("}\nfn c", "}\n\nfn c"), // not in an item
+    ];
+
+    for (replaced, text) in EDITS {
+        let result = reparse_for_test("fn a() { let x = 1; }\nfn c() {}\n", replaced, text);
+
+        assert_same_as_full(&result);
+        assert_ne!(result.incremental.id, result.old.id);
+    }
+}
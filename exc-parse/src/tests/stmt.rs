@@ -0,0 +1,313 @@
+use crate::{
+    tests::parse_module_for_test, ASTExprKind, ASTModuleItemKind, ASTPatternKind, ASTStmt,
+    ASTStmtKind,
+};
+
+/// Collects the statements of the first function.
+async fn stmts_for_test(content: &str) -> Vec<ASTStmt> {
+    let ast = parse_module_for_test(content).await;
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::FnDef(ast) => ast.stmt_block.stmts.clone(),
+        _ => panic!("expected fn module item"),
+    }
+}
+
+#[tokio::test]
+async fn test_stmt_for() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        for i in 0..10 {
+            print(i);
+        }
+        for item in items {}
+        for field in (S { a: 1 }).fields {}
+        for x in 0.. {
+            break;
+        }
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 4);
+
+    let fors = stmts
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ASTStmtKind::For(ast) => ast,
+            _ => panic!("expected for statement"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(fors[0].identifier.symbol.to_str(), "i");
+    assert!(matches!(fors[0].expr.kind, ASTExprKind::Range(_)));
+    assert_eq!(fors[0].stmt_block.stmts.len(), 1);
+
+    // `{` after the iterable begins the body, not a struct literal
+    assert!(matches!(fors[1].expr.kind, ASTExprKind::Path(_)));
+    assert!(matches!(fors[2].expr.kind, ASTExprKind::Member(_)));
+
+    match &fors[3].expr.kind {
+        ASTExprKind::Range(ast) => assert!(ast.end.is_none()),
+        _ => panic!("expected range expression"),
+    }
+    assert_eq!(fors[3].stmt_block.stmts.len(), 1);
+}
+
+#[tokio::test]
+async fn test_stmt_condition_without_struct_literal() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        if x {} else if y {}
+        while z {}
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 2);
+
+    match &stmts[0].kind {
+        ASTStmtKind::Expr(ast) => match &ast.expr.kind {
+            ASTExprKind::If(ast) => {
+                assert!(matches!(ast.expr.kind, ASTExprKind::Path(_)));
+                assert!(matches!(ast.else_ifs[0].expr.kind, ASTExprKind::Path(_)));
+            }
+            _ => panic!("expected if expression"),
+        },
+        _ => panic!("expected expression statement"),
+    }
+
+    match &stmts[1].kind {
+        ASTStmtKind::While(ast) => assert!(matches!(ast.expr.kind, ASTExprKind::Path(_))),
+        _ => panic!("expected while statement"),
+    }
+}
+
+#[tokio::test]
+async fn test_stmt_match() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        match x {
+            0 => print(0),
+            _ => {}
+        }
+        match x {
+            _ => {}
+        };
+        -1;
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 3);
+
+    // a match statement ends at its closing brace
+    for stmt in &stmts[..2] {
+        match &stmt.kind {
+            ASTStmtKind::Expr(ast) => assert!(matches!(ast.expr.kind, ASTExprKind::Match(_))),
+            _ => panic!("expected expression statement"),
+        }
+    }
+
+    match (&stmts[0].kind, &stmts[1].kind) {
+        (ASTStmtKind::Expr(first), ASTStmtKind::Expr(second)) => {
+            assert!(first.token_semicolon.is_none());
+            assert!(second.token_semicolon.is_some());
+        }
+        _ => unreachable!(),
+    }
+    assert!(
+        matches!(&stmts[2].kind, ASTStmtKind::Expr(ast) if matches!(ast.expr.kind, ASTExprKind::Unary(_)))
+    );
+}
+
+#[tokio::test]
+async fn test_stmt_let_pattern() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let x = 1;
+        let Point { x, y: (a, _) } = p;
+        let [first, _, last]: [i32; 3] = items;
+        let _;
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 4);
+
+    let lets = stmts
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ASTStmtKind::Let(ast) => ast,
+            _ => panic!("expected let statement"),
+        })
+        .collect::<Vec<_>>();
+
+    assert!(matches!(lets[0].pattern.kind, ASTPatternKind::Binding(_)));
+
+    match &lets[1].pattern.kind {
+        ASTPatternKind::Struct(ast) => {
+            let fields = ast
+                .fields
+                .items
+                .iter()
+                .map(|item| item.clone().into_item())
+                .collect::<Vec<_>>();
+
+            // a field without a pattern binds the field name
+            assert_eq!(fields.len(), 2);
+            assert!(fields[0].pattern.is_none());
+            assert!(matches!(
+                fields[1].pattern.as_ref().unwrap().pattern.kind,
+                ASTPatternKind::Tuple(_)
+            ));
+        }
+        _ => panic!("expected struct pattern"),
+    }
+
+    match &lets[2].pattern.kind {
+        ASTPatternKind::Array(ast) => assert_eq!(ast.items.items.len(), 3),
+        _ => panic!("expected array pattern"),
+    }
+    assert!(lets[2].ty.is_some());
+
+    assert!(matches!(lets[3].pattern.kind, ASTPatternKind::Wildcard(_)));
+    assert!(lets[3].expr.is_none());
+}
+
+#[tokio::test]
+async fn test_stmt_block_like_expr() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        let a = if x { 1 } else { 2 };
+        let b = loop {
+            break 3;
+        };
+        let c = { let d = 4; d * 2 };
+        if x {} else {}
+        -1;
+        loop { break; }
+        { f() }
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 7);
+
+    let exprs = stmts[..3]
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ASTStmtKind::Let(ast) => &ast.expr.as_ref().unwrap().expr,
+            _ => panic!("expected let statement"),
+        })
+        .collect::<Vec<_>>();
+
+    match &exprs[0].kind {
+        ASTExprKind::If(ast) => {
+            assert!(ast.stmt_block.tail_expr().is_some());
+            assert!(ast.else_.as_ref().unwrap().stmt_block.tail_expr().is_some());
+        }
+        _ => panic!("expected if expression"),
+    }
+    match &exprs[1].kind {
+        ASTExprKind::Loop(ast) => match &ast.stmt_block.stmts[0].kind {
+            ASTStmtKind::Break(ast) => assert!(ast.expr.is_some()),
+            _ => panic!("expected break statement"),
+        },
+        _ => panic!("expected loop expression"),
+    }
+    match &exprs[2].kind {
+        ASTExprKind::Block(ast) => {
+            assert_eq!(ast.stmts.len(), 2);
+            assert!(matches!(
+                ast.tail_expr().unwrap().kind,
+                ASTExprKind::Binary(_)
+            ));
+        }
+        _ => panic!("expected block expression"),
+    }
+
+    // a block-like expression ends the statement at its closing brace
+    let kinds = stmts[3..]
+        .iter()
+        .map(|stmt| match &stmt.kind {
+            ASTStmtKind::Expr(ast) => match &ast.expr.kind {
+                ASTExprKind::If(_) => "if",
+                ASTExprKind::Unary(_) => "unary",
+                ASTExprKind::Loop(_) => "loop",
+                ASTExprKind::Block(_) => "block",
+                _ => panic!("unexpected expression"),
+            },
+            _ => panic!("expected expression statement"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, vec!["if", "unary", "loop", "block"]);
+}
+
+#[tokio::test]
+async fn test_stmt_loop_label() {
+    const CONTENT: &str = r#"
+    fn foo() {
+        'outer: for i in items {
+            'inner: while x {
+                continue 'outer;
+            }
+        }
+        let a = 'found: loop {
+            break 'found 1;
+        };
+        loop { break; }
+    }
+"#;
+
+    let stmts = stmts_for_test(CONTENT).await;
+    assert_eq!(stmts.len(), 3);
+
+    let for_ = match &stmts[0].kind {
+        ASTStmtKind::For(ast) => ast,
+        _ => panic!("expected for statement"),
+    };
+    assert_eq!(for_.label.as_ref().unwrap().label.symbol.to_str(), "'outer");
+
+    let while_ = match &for_.stmt_block.stmts[0].kind {
+        ASTStmtKind::While(ast) => ast,
+        _ => panic!("expected while statement"),
+    };
+    assert_eq!(
+        while_.label.as_ref().unwrap().label.symbol.to_str(),
+        "'inner"
+    );
+
+    match &while_.stmt_block.stmts[0].kind {
+        ASTStmtKind::Continue(ast) => {
+            assert_eq!(ast.label.unwrap().symbol.to_str(), "'outer")
+        }
+        _ => panic!("expected continue statement"),
+    }
+
+    match &stmts[1].kind {
+        ASTStmtKind::Let(ast) => match &ast.expr.as_ref().unwrap().expr.kind {
+            ASTExprKind::Loop(ast) => {
+                assert_eq!(ast.label.as_ref().unwrap().label.symbol.to_str(), "'found");
+
+                match &ast.stmt_block.stmts[0].kind {
+                    ASTStmtKind::Break(ast) => {
+                        assert_eq!(ast.label.unwrap().symbol.to_str(), "'found");
+                        assert!(ast.expr.is_some());
+                    }
+                    _ => panic!("expected break statement"),
+                }
+            }
+            _ => panic!("expected loop expression"),
+        },
+        _ => panic!("expected let statement"),
+    }
+
+    match &stmts[2].kind {
+        ASTStmtKind::Expr(ast) => match &ast.expr.kind {
+            ASTExprKind::Loop(ast) => assert!(ast.label.is_none()),
+            _ => panic!("expected loop expression"),
+        },
+        _ => panic!("expected expression statement"),
+    }
+}
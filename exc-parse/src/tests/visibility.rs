@@ -0,0 +1,92 @@
+use crate::{
+    tests::parse_module_for_test, ASTModuleItemKind, ASTUsePathPrefixSegmentKind,
+    ASTVisibilityScopeKind,
+};
+
+#[tokio::test]
+async fn test_visibility() {
+    const CONTENT: &str = "
+    fn identifier() {}
+    pub fn identifier() {}
+    pub(super) struct identifier {}
+    pub(module) use path::identifier;
+    pub(in super::super::path) alias identifier = i32;
+    pub(in self) module identifier {}
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 6);
+
+    let visibilities = ast
+        .items
+        .iter()
+        .map(|item| match &item.kind {
+            ASTModuleItemKind::FnDef(ast) => &ast.visibility,
+            ASTModuleItemKind::StructDef(ast) => &ast.visibility,
+            ASTModuleItemKind::Use(ast) => &ast.visibility,
+            ASTModuleItemKind::AliasDef(ast) => &ast.visibility,
+            ASTModuleItemKind::ModuleDef(ast) => &ast.visibility,
+            _ => panic!("unexpected module item"),
+        })
+        .collect::<Vec<_>>();
+
+    assert!(visibilities[0].is_none());
+    assert!(visibilities[1].as_ref().unwrap().scope.is_none());
+
+    let scopes = visibilities[2..]
+        .iter()
+        .map(|visibility| &visibility.as_ref().unwrap().scope.as_ref().unwrap().kind)
+        .collect::<Vec<_>>();
+
+    assert!(matches!(scopes[0], ASTVisibilityScopeKind::Super_(_)));
+    assert!(matches!(scopes[1], ASTVisibilityScopeKind::Module(_)));
+
+    match scopes[2] {
+        ASTVisibilityScopeKind::In(ast) => {
+            let segments = ast
+                .path
+                .items
+                .iter()
+                .map(|segment| segment.clone().into_item())
+                .collect::<Vec<_>>();
+
+            assert_eq!(segments.len(), 3);
+            assert!(matches!(
+                segments[0],
+                ASTUsePathPrefixSegmentKind::Super_(_)
+            ));
+            assert!(matches!(
+                segments[1],
+                ASTUsePathPrefixSegmentKind::Super_(_)
+            ));
+            assert!(matches!(
+                segments[2],
+                ASTUsePathPrefixSegmentKind::Identifier(_)
+            ));
+        }
+        _ => panic!("expected `in` visibility"),
+    }
+
+    match scopes[3] {
+        ASTVisibilityScopeKind::In(ast) => assert_eq!(ast.path.items.len(), 1),
+        _ => panic!("expected `in` visibility"),
+    }
+}
+
+#[tokio::test]
+async fn test_visibility_extern_block_item() {
+    const CONTENT: &str = "
+    extern {
+        pub(super) prototype identifier();
+        pub(module) struct identifier {}
+    }
+";
+
+    let ast = parse_module_for_test(CONTENT).await;
+    assert_eq!(ast.items.len(), 1);
+
+    match &ast.items[0].kind {
+        ASTModuleItemKind::ExternBlock(ast) => assert_eq!(ast.items.len(), 2),
+        _ => panic!("expected extern module item"),
+    }
+}
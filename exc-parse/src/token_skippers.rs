@@ -1,6 +1,7 @@
 use crate::{
-    Token, TokenKind, KEYWORD_ALIAS, KEYWORD_FN, KEYWORD_IMPL, KEYWORD_INTERFACE, KEYWORD_MODULE,
-    KEYWORD_PROTOTYPE, KEYWORD_PUB, KEYWORD_STRUCT, KEYWORD_USE,
+    Token, TokenKind, KEYWORD_ALIAS, KEYWORD_CONST, KEYWORD_ENUM, KEYWORD_FN, KEYWORD_IMPL,
+    KEYWORD_INTERFACE, KEYWORD_MODULE, KEYWORD_PROTOTYPE, KEYWORD_PUB, KEYWORD_STATIC,
+    KEYWORD_STRUCT, KEYWORD_USE,
 };
 
 pub fn before_module_item(token: &Token) -> bool {
@@ -8,16 +9,29 @@ pub fn before_module_item(token: &Token) -> bool {
         TokenKind::Id { symbol } if symbol == *KEYWORD_PUB => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_USE => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_ALIAS => false,
+        TokenKind::Id { symbol } if symbol == *KEYWORD_CONST => false,
+        TokenKind::Id { symbol } if symbol == *KEYWORD_STATIC => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_MODULE => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_FN => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_STRUCT => false,
+        TokenKind::Id { symbol } if symbol == *KEYWORD_ENUM => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_INTERFACE => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_IMPL => false,
+        TokenKind::DocComment { .. } => false,
+        TokenKind::At => false,
         TokenKind::CloseBrace => false,
         _ => true,
     }
 }
 
+pub fn before_attribute_args_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseParen => false,
+        _ => true,
+    }
+}
+
 pub fn before_use_path_item_group_item(token: &Token) -> bool {
     match token.kind {
         TokenKind::Comma => false,
@@ -33,6 +47,8 @@ pub fn before_extern_block_item(token: &Token) -> bool {
         TokenKind::Id { symbol } if symbol == *KEYWORD_FN => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_STRUCT => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_IMPL => false,
+        TokenKind::DocComment { .. } => false,
+        TokenKind::At => false,
         TokenKind::CloseBrace => false,
         _ => true,
     }
@@ -62,10 +78,27 @@ pub fn before_struct_fields_item(token: &Token) -> bool {
     }
 }
 
+pub fn before_enum_variants_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseBrace => false,
+        _ => true,
+    }
+}
+
+pub fn before_enum_variant_tuple_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseParen => false,
+        _ => true,
+    }
+}
+
 pub fn before_interface_item(token: &Token) -> bool {
     match token.kind {
         TokenKind::Id { symbol } if symbol == *KEYWORD_PUB => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_FN => false,
+        TokenKind::DocComment { .. } => false,
         TokenKind::CloseBrace => false,
         _ => true,
     }
@@ -83,6 +116,8 @@ pub fn before_impl_block_item(token: &Token) -> bool {
     match token.kind {
         TokenKind::Id { symbol } if symbol == *KEYWORD_PUB => false,
         TokenKind::Id { symbol } if symbol == *KEYWORD_FN => false,
+        TokenKind::DocComment { .. } => false,
+        TokenKind::At => false,
         TokenKind::CloseBrace => false,
         _ => true,
     }
@@ -124,8 +159,11 @@ pub fn before_stmt(token: &Token) -> bool {
         TokenKind::CloseBrace => false,
         TokenKind::OpenBrace => false,
         TokenKind::Id { .. } => false,
+        TokenKind::Label { .. } => false,
         TokenKind::Literal(_) => false,
+        TokenKind::InterpolatedString { part, .. } if part.is_start() => false,
         TokenKind::OpenParen => false,
+        TokenKind::OpenBracket => false,
         _ => true,
     }
 }
@@ -145,6 +183,22 @@ pub fn before_expr_call_item(token: &Token) -> bool {
     }
 }
 
+pub fn before_expr_tuple_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseParen => false,
+        _ => true,
+    }
+}
+
+pub fn before_expr_array_literal_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseBracket => false,
+        _ => true,
+    }
+}
+
 pub fn before_expr_struct_literal_field_item(token: &Token) -> bool {
     match token.kind {
         TokenKind::Comma => false,
@@ -153,6 +207,46 @@ pub fn before_expr_struct_literal_field_item(token: &Token) -> bool {
     }
 }
 
+pub fn before_expr_match_arm_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseBrace => false,
+        _ => true,
+    }
+}
+
+pub fn before_pattern_struct_field_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseBrace => false,
+        _ => true,
+    }
+}
+
+pub fn before_pattern_tuple_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseParen => false,
+        _ => true,
+    }
+}
+
+pub fn before_pattern_array_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseBracket => false,
+        _ => true,
+    }
+}
+
+pub fn before_ty_tuple_item(token: &Token) -> bool {
+    match token.kind {
+        TokenKind::Comma => false,
+        TokenKind::CloseParen => false,
+        _ => true,
+    }
+}
+
 pub fn before_ty_fn_pointer_param_item(token: &Token) -> bool {
     match token.kind {
         TokenKind::Comma => false,
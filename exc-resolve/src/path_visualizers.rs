@@ -1,7 +1,7 @@
 use exc_parse::ASTUsePathPrefixSegmentKind;
 use exc_symbol::Symbol;
 
-use crate::GlobalSymbol;
+use crate::{GlobalSymbol, GlobalSymbolKind};
 
 pub fn visualize_prefix(prefix: &[ASTUsePathPrefixSegmentKind]) -> String {
     prefix
@@ -19,11 +19,19 @@ pub fn visualize_module_path(path: &[Symbol]) -> String {
 }
 
 pub fn visualize_global_symbol_path(global_symbol: &GlobalSymbol) -> String {
+    // variants are accessed through their enum
+    let enum_identifier = match &global_symbol.kind {
+        GlobalSymbolKind::EnumVariant(variant) => Some(variant.enum_def.identifier.symbol),
+        _ => None,
+    };
+
     global_symbol
         .module
         .path
         .iter()
-        .chain(std::iter::once(&global_symbol.kind.identifier().symbol))
+        .copied()
+        .chain(enum_identifier)
+        .chain(std::iter::once(global_symbol.kind.identifier().symbol))
         .map(|symbol| symbol.to_str())
         .collect::<Vec<_>>()
         .join("::")
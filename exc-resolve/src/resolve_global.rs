@@ -1,19 +1,27 @@
+mod abi;
+mod confusable_identifiers;
 mod diagnostics_receiver;
 mod global_symbol_registry;
 mod module;
 mod module_registry;
+mod namespace;
 mod redirect_registry;
 mod resolve_module_decl;
 mod source_file_resolver;
+mod unknown_attributes;
 mod visibility;
 
+pub use abi::*;
+pub use confusable_identifiers::*;
 pub use diagnostics_receiver::*;
 pub use global_symbol_registry::*;
 pub use module::*;
 pub use module_registry::*;
+pub use namespace::*;
 pub use redirect_registry::*;
 pub use resolve_module_decl::*;
 pub use source_file_resolver::*;
+pub use unknown_attributes::*;
 pub use visibility::*;
 
 pub async fn resolve_global(
@@ -33,6 +41,15 @@ pub async fn resolve_global(
         global_symbol_registry.register_module(module);
     }
 
+    // checked before the redirects are resolved, so that only the symbols defined in each module are compared
+    for module in module_registry.modules() {
+        check_confusable_identifiers(&global_symbol_registry, module);
+    }
+
+    for module in module_registry.modules() {
+        check_unknown_attributes(module);
+    }
+
     let mut redirect_registry = RedirectRegistry::new();
 
     for module in module_registry.modules() {
@@ -0,0 +1,30 @@
+/// The calling convention of the functions declared in an extern block.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Abi {
+    /// `extern "C"`; also used if the ABI is omitted
+    #[default]
+    C,
+    /// `extern "system"`; `stdcall` on 32-bit Windows and `C` everywhere else
+    System,
+    /// `extern "stdcall"`
+    Stdcall,
+    /// `extern "fastcall"`
+    Fastcall,
+}
+
+impl Abi {
+    pub const ALL: [Abi; 4] = [Abi::C, Abi::System, Abi::Stdcall, Abi::Fastcall];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|abi| abi.name() == name)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Abi::C => "C",
+            Abi::System => "system",
+            Abi::Stdcall => "stdcall",
+            Abi::Fastcall => "fastcall",
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{GlobalSymbol, GlobalSymbolRegistry, Module};
+use exc_diagnostic::warning_codes;
+use std::collections::{hash_map::Entry, HashMap};
+use unicode_security::confusable_detection::skeleton;
+
+/// Warns about symbols defined in the module whose identifiers differ but look the same,
+/// e.g. Latin `a` and Cyrillic `а`. Two identifiers are confusable if they have the same Unicode skeleton.
+pub fn check_confusable_identifiers(
+    global_symbol_registry: &GlobalSymbolRegistry,
+    module: &Module,
+) {
+    let mut symbols = global_symbol_registry.any_symbols(module);
+    symbols.sort_by_key(|symbol| symbol.identifier.span.low);
+
+    let mut skeletons = HashMap::<String, &GlobalSymbol>::new();
+
+    for symbol in symbols {
+        match skeletons.entry(skeleton(symbol.identifier.symbol.to_str()).collect()) {
+            Entry::Occupied(entry) => {
+                let previous = entry.get();
+
+                if previous.identifier.symbol == symbol.identifier.symbol {
+                    // e.g. a module and a function with the same name; they are not confusing
+                    continue;
+                }
+
+                module.diagnostics.warning_sub(
+                    warning_codes::CONFUSABLE_IDENTIFIERS,
+                    symbol.identifier.span,
+                    format!(
+                        "the identifier {} looks the same as {}",
+                        symbol.identifier.symbol, previous.identifier.symbol
+                    ),
+                    vec![module.diagnostics.sub_hint(
+                        previous.identifier.span,
+                        format!("{} is defined here", previous.identifier.symbol),
+                    )],
+                );
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(symbol);
+            }
+        }
+    }
+}
@@ -1,7 +1,9 @@
-use crate::{Module, Visibility};
+use crate::{resolve_visibility, Abi, Module, Visibility};
 use exc_parse::{
-    ASTAliasDef, ASTExternBlock, ASTExternBlockItemKind, ASTFnDef, ASTInterfaceDef, ASTModuleDecl,
-    ASTModuleDef, ASTModuleItemKind, ASTPrototypeDef, ASTStructDef, Id, NodeId,
+    ASTAliasDef, ASTAttribute, ASTConstDef, ASTEnumDef, ASTEnumDefVariant, ASTExprKind,
+    ASTExternBlock, ASTExternBlockItemKind, ASTFnDef, ASTInterfaceDef, ASTModuleDecl, ASTModuleDef,
+    ASTModuleItemKind, ASTPrototypeDef, ASTStaticDef, ASTStructDef, Id, NodeId, PunctuatedItem,
+    TokenLiteralValue, ATTRIBUTE_LINK_NAME,
 };
 use exc_symbol::Symbol;
 use std::{
@@ -71,9 +73,13 @@ pub enum GlobalSymbolKind {
     ModuleDecl(Arc<ASTModuleDecl>),
     ModuleDef(Arc<ASTModuleDef>),
     Alias(Arc<ASTAliasDef>),
-    Prototype(Arc<ASTPrototypeDef>),
+    Const(Arc<ASTConstDef>),
+    Static(Arc<ASTStaticDef>),
+    Prototype(GlobalSymbolPrototype),
     Fn(Arc<ASTFnDef>),
     Struct(Arc<ASTStructDef>),
+    Enum(Arc<ASTEnumDef>),
+    EnumVariant(GlobalSymbolEnumVariant),
     Interface(Arc<ASTInterfaceDef>),
 }
 
@@ -87,9 +93,13 @@ impl GlobalSymbolKind {
             GlobalSymbolKind::ModuleDecl(ast) => ast.id,
             GlobalSymbolKind::ModuleDef(ast) => ast.id,
             GlobalSymbolKind::Alias(ast) => ast.id,
-            GlobalSymbolKind::Prototype(ast) => ast.id,
+            GlobalSymbolKind::Const(ast) => ast.id,
+            GlobalSymbolKind::Static(ast) => ast.id,
+            GlobalSymbolKind::Prototype(prototype) => prototype.prototype_def.id,
             GlobalSymbolKind::Fn(ast) => ast.id,
             GlobalSymbolKind::Struct(ast) => ast.id,
+            GlobalSymbolKind::Enum(ast) => ast.id,
+            GlobalSymbolKind::EnumVariant(variant) => variant.variant().id,
             GlobalSymbolKind::Interface(ast) => ast.id,
         }
     }
@@ -99,14 +109,57 @@ impl GlobalSymbolKind {
             GlobalSymbolKind::ModuleDecl(ast) => ast.identifier,
             GlobalSymbolKind::ModuleDef(ast) => ast.identifier,
             GlobalSymbolKind::Alias(ast) => ast.identifier,
-            GlobalSymbolKind::Prototype(ast) => ast.identifier,
+            GlobalSymbolKind::Const(ast) => ast.identifier,
+            GlobalSymbolKind::Static(ast) => ast.identifier,
+            GlobalSymbolKind::Prototype(prototype) => prototype.prototype_def.identifier,
             GlobalSymbolKind::Fn(ast) => ast.identifier,
             GlobalSymbolKind::Struct(ast) => ast.identifier,
+            GlobalSymbolKind::Enum(ast) => ast.identifier,
+            GlobalSymbolKind::EnumVariant(variant) => variant.variant().identifier,
             GlobalSymbolKind::Interface(ast) => ast.identifier,
         }
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct GlobalSymbolPrototype {
+    pub prototype_def: Arc<ASTPrototypeDef>,
+    /// the ABI of the enclosing extern block
+    pub abi: Abi,
+    /// the name of the symbol to bind; the identifier unless overridden with `@link_name("...")`
+    pub link_name: Symbol,
+}
+
+impl GlobalSymbolPrototype {
+    pub fn new(prototype_def: Arc<ASTPrototypeDef>, abi: Abi, link_name: Symbol) -> Self {
+        Self {
+            prototype_def,
+            abi,
+            link_name,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GlobalSymbolEnumVariant {
+    pub enum_def: Arc<ASTEnumDef>,
+    /// the index of the variant in the enum definition
+    pub index: usize,
+}
+
+impl GlobalSymbolEnumVariant {
+    pub fn new(enum_def: Arc<ASTEnumDef>, index: usize) -> Self {
+        Self { enum_def, index }
+    }
+
+    pub fn variant(&self) -> &ASTEnumDefVariant {
+        match &self.enum_def.variants.items[self.index] {
+            PunctuatedItem::Punctuated { item, .. } => item,
+            PunctuatedItem::NotPunctuated { item } => item,
+        }
+    }
+}
+
 impl From<Arc<ASTModuleDecl>> for GlobalSymbolKind {
     fn from(ast: Arc<ASTModuleDecl>) -> Self {
         Self::ModuleDecl(ast)
@@ -125,9 +178,21 @@ impl From<Arc<ASTAliasDef>> for GlobalSymbolKind {
     }
 }
 
-impl From<Arc<ASTPrototypeDef>> for GlobalSymbolKind {
-    fn from(ast: Arc<ASTPrototypeDef>) -> Self {
-        Self::Prototype(ast)
+impl From<Arc<ASTConstDef>> for GlobalSymbolKind {
+    fn from(ast: Arc<ASTConstDef>) -> Self {
+        Self::Const(ast)
+    }
+}
+
+impl From<Arc<ASTStaticDef>> for GlobalSymbolKind {
+    fn from(ast: Arc<ASTStaticDef>) -> Self {
+        Self::Static(ast)
+    }
+}
+
+impl From<GlobalSymbolPrototype> for GlobalSymbolKind {
+    fn from(prototype: GlobalSymbolPrototype) -> Self {
+        Self::Prototype(prototype)
     }
 }
 
@@ -143,6 +208,18 @@ impl From<Arc<ASTStructDef>> for GlobalSymbolKind {
     }
 }
 
+impl From<Arc<ASTEnumDef>> for GlobalSymbolKind {
+    fn from(ast: Arc<ASTEnumDef>) -> Self {
+        Self::Enum(ast)
+    }
+}
+
+impl From<GlobalSymbolEnumVariant> for GlobalSymbolKind {
+    fn from(variant: GlobalSymbolEnumVariant) -> Self {
+        Self::EnumVariant(variant)
+    }
+}
+
 impl From<Arc<ASTInterfaceDef>> for GlobalSymbolKind {
     fn from(ast: Arc<ASTInterfaceDef>) -> Self {
         Self::Interface(ast)
@@ -153,6 +230,8 @@ impl From<Arc<ASTInterfaceDef>> for GlobalSymbolKind {
 pub struct GlobalSymbolRegistry {
     module_symbols: HashMap<NodeId, HashMap<Symbol, GlobalSymbol>>,
     non_module_symbols: HashMap<NodeId, HashMap<Symbol, GlobalSymbol>>,
+    /// variants of each enum, keyed by the id of the enum definition
+    enum_variants: HashMap<NodeId, HashMap<Symbol, GlobalSymbol>>,
 }
 
 impl GlobalSymbolRegistry {
@@ -200,39 +279,73 @@ impl GlobalSymbolRegistry {
             .and_then(|map| map.get(&symbol))
     }
 
+    pub fn enum_variants(
+        &self,
+        enum_def: &ASTEnumDef,
+    ) -> Option<impl Iterator<Item = &GlobalSymbol>> {
+        self.enum_variants
+            .get(&enum_def.id)
+            .map(|symbols| symbols.values())
+    }
+
+    pub fn get_enum_variant(&self, enum_def: &ASTEnumDef, symbol: Symbol) -> Option<&GlobalSymbol> {
+        self.enum_variants
+            .get(&enum_def.id)
+            .and_then(|map| map.get(&symbol))
+    }
+
     pub fn register_module(&mut self, module: &Arc<Module>) {
         for item in module.ast.items() {
-            let (is_pub, kind): (_, GlobalSymbolKind) = match &item.kind {
+            let (visibility, kind): (_, GlobalSymbolKind) = match &item.kind {
                 ASTModuleItemKind::Use(_) => continue,
-                ASTModuleItemKind::AliasDef(ast) => (ast.keyword_pub.is_some(), ast.clone().into()),
-                ASTModuleItemKind::ModuleDecl(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
-                ASTModuleItemKind::ModuleDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
+                ASTModuleItemKind::AliasDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::ConstDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::StaticDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::ModuleDecl(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::ModuleDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
                 ASTModuleItemKind::ExternBlock(ast) => {
                     self.register_extern_block(module, ast);
                     continue;
                 }
-                ASTModuleItemKind::FnDef(ast) => (ast.keyword_pub.is_some(), ast.clone().into()),
-                ASTModuleItemKind::StructDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
-                ASTModuleItemKind::InterfaceDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
+                ASTModuleItemKind::FnDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::StructDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTModuleItemKind::EnumDef(ast) => {
+                    let visibility = resolve_visibility(module, ast.visibility.as_ref());
+                    self.register_enum_variants(module, ast, &visibility);
+                    (visibility, ast.clone().into())
                 }
+                ASTModuleItemKind::InterfaceDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
                 ASTModuleItemKind::ImplBlock(_) => continue,
             };
 
             let identifier = kind.identifier();
             self.register(GlobalSymbol::new(
                 SymbolLevel::Explicit,
-                if is_pub {
-                    Visibility::Public
-                } else {
-                    Visibility::Private
-                },
+                visibility,
                 module.clone(),
                 kind,
                 identifier,
@@ -242,28 +355,34 @@ impl GlobalSymbolRegistry {
     }
 
     fn register_extern_block(&mut self, module: &Arc<Module>, ast: &ASTExternBlock) {
+        let abi = extern_block_abi(module, ast);
+
         for item in &ast.items {
-            let (is_pub, kind): (_, GlobalSymbolKind) = match &item.kind {
-                ASTExternBlockItemKind::PrototypeDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
-                ASTExternBlockItemKind::FnDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
-                ASTExternBlockItemKind::StructDef(ast) => {
-                    (ast.keyword_pub.is_some(), ast.clone().into())
-                }
+            let (visibility, kind): (_, GlobalSymbolKind) = match &item.kind {
+                ASTExternBlockItemKind::PrototypeDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    GlobalSymbolPrototype::new(
+                        ast.clone(),
+                        abi,
+                        link_name(module, &item.attributes).unwrap_or(ast.identifier.symbol),
+                    )
+                    .into(),
+                ),
+                ASTExternBlockItemKind::FnDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
+                ASTExternBlockItemKind::StructDef(ast) => (
+                    resolve_visibility(module, ast.visibility.as_ref()),
+                    ast.clone().into(),
+                ),
                 ASTExternBlockItemKind::ImplBlock(_) => continue,
             };
 
             let identifier = kind.identifier();
             self.register(GlobalSymbol::new(
                 SymbolLevel::Explicit,
-                if is_pub {
-                    Visibility::Public
-                } else {
-                    Visibility::Private
-                },
+                visibility,
                 module.clone(),
                 kind,
                 identifier,
@@ -272,13 +391,42 @@ impl GlobalSymbolRegistry {
         }
     }
 
+    /// Registers the variants of the enum; they have the same visibility as the enum.
+    fn register_enum_variants(
+        &mut self,
+        module: &Arc<Module>,
+        ast: &Arc<ASTEnumDef>,
+        visibility: &Visibility,
+    ) {
+        for index in 0..ast.variants.items.len() {
+            let kind: GlobalSymbolKind = GlobalSymbolEnumVariant::new(ast.clone(), index).into();
+            let identifier = kind.identifier();
+            register_into(
+                &mut self.enum_variants,
+                ast.id,
+                GlobalSymbol::new(
+                    SymbolLevel::Explicit,
+                    visibility.clone(),
+                    module.clone(),
+                    kind,
+                    identifier,
+                    module.clone(),
+                ),
+            );
+        }
+    }
+
     pub fn register(&mut self, symbol: GlobalSymbol) -> bool {
+        // keyed by the module that refers to the symbol, e.g. the importing module of a glob import;
+        // not by `identifier_module`, which is the module that defines it
+        let key = symbol.module.ast.id();
         register_into(
             if symbol.kind.is_module() {
                 &mut self.module_symbols
             } else {
                 &mut self.non_module_symbols
             },
+            key,
             symbol,
         )
     }
@@ -286,13 +434,10 @@ impl GlobalSymbolRegistry {
 
 fn register_into(
     map: &mut HashMap<NodeId, HashMap<Symbol, GlobalSymbol>>,
+    key: NodeId,
     symbol: GlobalSymbol,
 ) -> bool {
-    match map
-        .entry(symbol.identifier_module.ast.id())
-        .or_default()
-        .entry(symbol.identifier.symbol)
-    {
+    match map.entry(key).or_default().entry(symbol.identifier.symbol) {
         Entry::Occupied(mut entry) => {
             let previous = entry.get();
 
@@ -317,6 +462,72 @@ fn register_into(
     }
 }
 
+/// Returns the ABI of the extern block, or the default one if it's omitted or unknown.
+fn extern_block_abi(module: &Module, ast: &ASTExternBlock) -> Abi {
+    let abi = match &ast.abi {
+        Some(abi) => abi,
+        None => return Abi::default(),
+    };
+
+    if let Some(TokenLiteralValue::String(name)) = abi.literal.value {
+        if let Some(abi) = Abi::from_name(name.to_str()) {
+            return abi;
+        }
+    }
+
+    module.diagnostics.error_sub(
+        exc_diagnostic::error_codes::UNKNOWN_ABI,
+        abi.span,
+        format!("the ABI {} is unknown", abi.literal.content),
+        vec![module.diagnostics.sub_hint_simple(format!(
+            "the known ABIs are {}",
+            Abi::ALL
+                .iter()
+                .map(|abi| format!("`\"{}\"`", abi.name()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))],
+    );
+
+    Abi::default()
+}
+
+/// Returns the name given by the `@link_name("...")` attribute, if any.
+fn link_name(module: &Module, attributes: &[ASTAttribute]) -> Option<Symbol> {
+    let attribute = attributes
+        .iter()
+        .rev()
+        .find(|attribute| attribute.identifier.symbol == *ATTRIBUTE_LINK_NAME)?;
+
+    if let Some(args) = &attribute.args {
+        if let [PunctuatedItem::NotPunctuated { item } | PunctuatedItem::Punctuated { item, .. }] =
+            args.args.items.as_slice()
+        {
+            if let ASTExprKind::Literal(ast) = &item.kind {
+                if let Some(TokenLiteralValue::String(name)) = ast.literal.value {
+                    if !name.to_str().is_empty() {
+                        return Some(name);
+                    }
+                }
+            }
+        }
+    }
+
+    module.diagnostics.error_sub(
+        exc_diagnostic::error_codes::INVALID_LINK_NAME,
+        attribute.span,
+        format!(
+            "the attribute {} requires a name",
+            attribute.identifier.symbol
+        ),
+        vec![module
+            .diagnostics
+            .sub_hint_simple("consider giving a string, e.g. `@link_name(\"puts\")`".to_owned())],
+    );
+
+    None
+}
+
 fn emit_conflict_error(symbol: &GlobalSymbol, previous: &GlobalSymbol) {
     symbol.identifier_module.diagnostics.error_sub(
         exc_diagnostic::error_codes::DUPLICATED_SYMBOL,
@@ -328,7 +539,7 @@ fn emit_conflict_error(symbol: &GlobalSymbol, previous: &GlobalSymbol) {
         vec![{
             previous.module.diagnostics.sub_hint(
                 previous.identifier.span,
-                format!("previous definition here"),
+                "previous definition here".to_owned(),
             )
         }],
     );
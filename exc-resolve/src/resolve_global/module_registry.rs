@@ -79,11 +79,12 @@ impl ModuleRegistry {
                 }
                 Entry::Vacant(entry) => {
                     let module = Arc::new(Module {
-                        visibility: if submodule.keyword_pub.is_some() {
-                            Visibility::Public
-                        } else {
-                            Visibility::Private
-                        },
+                        // the errors are reported when the module is registered as a symbol
+                        visibility: Visibility::from_ast(
+                            &module.path,
+                            submodule.visibility.as_ref(),
+                        )
+                        .unwrap_or(Visibility::Private),
                         ast: ModuleASTKind::Submodule(submodule),
                         path,
                         file: module.file.clone(),
@@ -114,11 +115,14 @@ fn collect_submodules<'a>(
         let submodule = match &item.kind {
             ASTModuleItemKind::Use(_) => continue,
             ASTModuleItemKind::AliasDef(_) => continue,
+            ASTModuleItemKind::ConstDef(_) => continue,
+            ASTModuleItemKind::StaticDef(_) => continue,
             ASTModuleItemKind::ModuleDecl(_) => continue,
             ASTModuleItemKind::ModuleDef(ast) => ast,
             ASTModuleItemKind::ExternBlock(_) => continue,
             ASTModuleItemKind::FnDef(_) => continue,
             ASTModuleItemKind::StructDef(_) => continue,
+            ASTModuleItemKind::EnumDef(_) => continue,
             ASTModuleItemKind::InterfaceDef(_) => continue,
             ASTModuleItemKind::ImplBlock(_) => continue,
         };
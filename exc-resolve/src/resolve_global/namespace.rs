@@ -0,0 +1,78 @@
+use crate::{GlobalSymbol, GlobalSymbolKind, GlobalSymbolRegistry, Module, ModuleRegistry};
+use exc_parse::ASTEnumDef;
+use exc_symbol::Symbol;
+use std::sync::Arc;
+
+/// A scope whose symbols can be accessed by paths, e.g. `module::Symbol` or `Enum::Variant`.
+#[derive(Debug, Clone)]
+pub enum Namespace {
+    Module(Arc<Module>),
+    Enum(Arc<ASTEnumDef>),
+}
+
+impl Namespace {
+    /// Returns the namespace that the given symbol introduces, if any.
+    pub fn from_global_symbol(
+        module_registry: &ModuleRegistry,
+        global_symbol: &GlobalSymbol,
+    ) -> Option<Self> {
+        match &global_symbol.kind {
+            GlobalSymbolKind::ModuleDecl(ast) => module_registry
+                .get_module_by_id(ast.id)
+                .map(|module| Self::Module(module.clone())),
+            GlobalSymbolKind::ModuleDef(ast) => module_registry
+                .get_module_by_id(ast.id)
+                .map(|module| Self::Module(module.clone())),
+            GlobalSymbolKind::Enum(ast) => Some(Self::Enum(ast.clone())),
+            _ => None,
+        }
+    }
+
+    pub fn any_symbols<'a>(&self, registry: &'a GlobalSymbolRegistry) -> Vec<&'a GlobalSymbol> {
+        match self {
+            Self::Module(module) => registry.any_symbols(module),
+            Self::Enum(ast) => registry
+                .enum_variants(ast)
+                .map(|symbols| symbols.collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn non_module_symbols<'a>(
+        &self,
+        registry: &'a GlobalSymbolRegistry,
+    ) -> Vec<&'a GlobalSymbol> {
+        match self {
+            Self::Module(module) => registry
+                .non_module_symbols(module)
+                .map(|symbols| symbols.collect())
+                .unwrap_or_default(),
+            Self::Enum(ast) => registry
+                .enum_variants(ast)
+                .map(|symbols| symbols.collect())
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn get_any_symbol<'a>(
+        &self,
+        registry: &'a GlobalSymbolRegistry,
+        symbol: Symbol,
+    ) -> Option<&'a GlobalSymbol> {
+        match self {
+            Self::Module(module) => registry.get_any_symbol(module, symbol),
+            Self::Enum(ast) => registry.get_enum_variant(ast, symbol),
+        }
+    }
+
+    pub fn get_non_module_symbol<'a>(
+        &self,
+        registry: &'a GlobalSymbolRegistry,
+        symbol: Symbol,
+    ) -> Option<&'a GlobalSymbol> {
+        match self {
+            Self::Module(module) => registry.get_non_module_symbol(module, symbol),
+            Self::Enum(ast) => registry.get_enum_variant(ast, symbol),
+        }
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
-    visualize_global_symbol_path, visualize_module_path, visualize_prefix, GlobalSymbol,
-    GlobalSymbolKind, GlobalSymbolRegistry, Module, ModuleRegistry, SymbolLevel, Visibility,
+    resolve_visibility, visualize_global_symbol_path, visualize_module_path, visualize_prefix,
+    GlobalSymbol, GlobalSymbolKind, GlobalSymbolRegistry, Module, ModuleRegistry, Namespace,
+    SymbolLevel, Visibility,
 };
+use exc_diagnostic::SubDiagnostics;
 use exc_parse::{
     ASTModuleItemKind, ASTUsePath, ASTUsePathItemKind, ASTUsePathPrefixSegmentKind, Id, NodeId,
     PunctuatedItem,
@@ -70,15 +72,15 @@ pub struct RedirectSingleTarget {
 #[derive(Debug, Clone)]
 pub struct GlobRedirect {
     pub module: Arc<Module>,
-    pub target_module: Arc<Module>,
+    pub target: Namespace,
     pub visibility: Visibility,
 }
 
 impl GlobRedirect {
-    pub fn new(module: Arc<Module>, target_module: Arc<Module>, visibility: Visibility) -> Self {
+    pub fn new(module: Arc<Module>, target: Namespace, visibility: Visibility) -> Self {
         Self {
             module,
-            target_module,
+            target,
             visibility,
         }
     }
@@ -99,22 +101,21 @@ impl RedirectRegistry {
             let ast = match &item.kind {
                 ASTModuleItemKind::Use(ast) => ast,
                 ASTModuleItemKind::AliasDef(_) => continue,
+                ASTModuleItemKind::ConstDef(_) => continue,
+                ASTModuleItemKind::StaticDef(_) => continue,
                 ASTModuleItemKind::ModuleDecl(_) => continue,
                 ASTModuleItemKind::ModuleDef(_) => continue,
                 ASTModuleItemKind::ExternBlock(_) => continue,
                 ASTModuleItemKind::FnDef(_) => continue,
                 ASTModuleItemKind::StructDef(_) => continue,
+                ASTModuleItemKind::EnumDef(_) => continue,
                 ASTModuleItemKind::InterfaceDef(_) => continue,
                 ASTModuleItemKind::ImplBlock(_) => continue,
             };
 
             self.collect_redirects_items(
                 module,
-                if ast.keyword_pub.is_some() {
-                    Visibility::Public
-                } else {
-                    Visibility::Private
-                },
+                &resolve_visibility(module, ast.visibility.as_ref()),
                 &[],
                 vec![&ast.path],
             );
@@ -124,7 +125,7 @@ impl RedirectRegistry {
     fn collect_redirects_items(
         &mut self,
         module: &Arc<Module>,
-        visibility: Visibility,
+        visibility: &Visibility,
         base_prefix: &[ASTUsePathPrefixSegmentKind],
         paths: Vec<&ASTUsePath>,
     ) {
@@ -141,7 +142,7 @@ impl RedirectRegistry {
                 ASTUsePathItemKind::All(_) => {
                     let redirect = Redirect::new(
                         module.clone(),
-                        visibility,
+                        visibility.clone(),
                         full_prefix,
                         RedirectTarget::all(),
                         path.span,
@@ -155,7 +156,7 @@ impl RedirectRegistry {
                 ASTUsePathItemKind::Single(ast) => {
                     let redirect = Redirect::new(
                         module.clone(),
-                        visibility,
+                        visibility.clone(),
                         full_prefix,
                         RedirectTarget::single(
                             ast.identifier,
@@ -295,8 +296,8 @@ impl RedirectRegistry {
                         RedirectTarget::Single(single_target) => single_target,
                     };
 
-                    let global_symbol = match global_symbol_registry
-                        .get_any_symbol(target_module, single_target.identifier.symbol)
+                    let global_symbol = match target_module
+                        .get_any_symbol(global_symbol_registry, single_target.identifier.symbol)
                     {
                         Some(global_symbol) => global_symbol,
                         None => {
@@ -306,10 +307,14 @@ impl RedirectRegistry {
                         }
                     };
 
-                    if check_global_symbol_visibility(&redirect.module, &global_symbol) {
+                    if check_global_symbol_visibility(
+                        &redirect.module,
+                        global_symbol,
+                        single_target.identifier.span,
+                    ) {
                         let added = global_symbol_registry.register(GlobalSymbol::new(
                             SymbolLevel::Explicit,
-                            redirect.visibility,
+                            redirect.visibility.clone(),
                             redirect.module.clone(),
                             global_symbol.kind.clone(),
                             single_target.alias.unwrap_or(single_target.identifier),
@@ -381,8 +386,8 @@ impl RedirectRegistry {
 
                     glob_redirects.push(GlobRedirect::new(
                         redirect.module.clone(),
-                        target_module.clone(),
-                        redirect.visibility,
+                        target_module,
+                        redirect.visibility.clone(),
                     ));
 
                     // glob redirects should not be removed,
@@ -395,17 +400,17 @@ impl RedirectRegistry {
 
                 let module = module_registry.get_module_by_id(*module_id).unwrap();
                 let blocklist =
-                    build_glob_import_blocklist(global_symbol_registry, &module, &glob_redirects);
+                    build_glob_import_blocklist(global_symbol_registry, module, &glob_redirects);
 
                 for glob_redirect in glob_redirects {
                     let mut redirected = Vec::new();
 
-                    let global_symbols =
-                        global_symbol_registry.any_symbols(&glob_redirect.target_module);
+                    let global_symbols = glob_redirect.target.any_symbols(global_symbol_registry);
 
                     for global_symbol in global_symbols {
-                        if global_symbol.visibility == Visibility::Private
-                            && !Arc::ptr_eq(&module.file, &global_symbol.module.file)
+                        if !global_symbol
+                            .visibility
+                            .is_visible(&global_symbol.module, module)
                         {
                             continue;
                         }
@@ -420,7 +425,7 @@ impl RedirectRegistry {
                     for global_symbol in redirected {
                         let added = global_symbol_registry.register(GlobalSymbol::new(
                             SymbolLevel::Glob,
-                            glob_redirect.visibility,
+                            glob_redirect.visibility.clone(),
                             module.clone(),
                             global_symbol.kind.clone(),
                             global_symbol.kind.identifier(),
@@ -447,11 +452,11 @@ fn resolve_target_module<'a>(
     global_symbol_registry: &'a GlobalSymbolRegistry,
     module: &'a Arc<Module>,
     prefix: &[ASTUsePathPrefixSegmentKind],
-) -> Result<Option<&'a Arc<Module>>, ()> {
+) -> Result<Option<Namespace>, ()> {
     let mut target_module = module;
 
     if !prefix.is_empty() {
-        for segment in prefix {
+        for (index, segment) in prefix.iter().enumerate() {
             match segment {
                 ASTUsePathPrefixSegmentKind::Self_(_) => continue,
                 ASTUsePathPrefixSegmentKind::Super_(id) => {
@@ -475,6 +480,16 @@ fn resolve_target_module<'a>(
                         match global_symbol_registry.get_module_symbol(target_module, id.symbol) {
                             Some(global_symbol) => global_symbol,
                             None => {
+                                // the last segment can be an enum, to refer its variants
+                                if index + 1 == prefix.len() {
+                                    return resolve_target_enum(
+                                        global_symbol_registry,
+                                        module,
+                                        target_module,
+                                        *id,
+                                    );
+                                }
+
                                 return Ok(None);
                             }
                         };
@@ -505,7 +520,35 @@ fn resolve_target_module<'a>(
         }
     }
 
-    Ok(Some(target_module))
+    Ok(Some(Namespace::Module(target_module.clone())))
+}
+
+fn resolve_target_enum(
+    global_symbol_registry: &GlobalSymbolRegistry,
+    module: &Module,
+    target_module: &Module,
+    id: Id,
+) -> Result<Option<Namespace>, ()> {
+    let global_symbol = match global_symbol_registry.get_non_module_symbol(target_module, id.symbol)
+    {
+        Some(global_symbol) => global_symbol,
+        None => {
+            return Ok(None);
+        }
+    };
+
+    let ast = match &global_symbol.kind {
+        GlobalSymbolKind::Enum(ast) => ast,
+        _ => {
+            return Ok(None);
+        }
+    };
+
+    if !check_global_symbol_visibility(module, global_symbol, id.span) {
+        return Err(());
+    }
+
+    Ok(Some(Namespace::Enum(ast.clone())))
 }
 
 fn check_module_has_super(module: &Module, target_module: &Module, id: Id) -> bool {
@@ -527,9 +570,7 @@ fn check_module_has_super(module: &Module, target_module: &Module, id: Id) -> bo
 }
 
 fn check_module_visibility(module: &Module, target_module: &Module, span: Span) -> bool {
-    if target_module.visibility == Visibility::Public
-        || Arc::ptr_eq(&module.file, &target_module.file)
-    {
+    if target_module.visibility.is_visible(target_module, module) {
         return true;
     }
 
@@ -538,38 +579,49 @@ fn check_module_visibility(module: &Module, target_module: &Module, span: Span)
         exc_diagnostic::error_codes::MODULE_IS_NOT_VISIBLE,
         span,
         format!("the module `{}` is not visible from this module", path),
-        vec![{
-            module.diagnostics.sub_hint_simple(format!(
-                "consider making the module public, or accessing it in same file"
-            ))
-        }],
+        vec![visibility_hint(module, &target_module.visibility, "module")],
     );
 
     false
 }
 
-fn check_global_symbol_visibility(module: &Module, global_symbol: &GlobalSymbol) -> bool {
-    if global_symbol.visibility == Visibility::Public
-        || Arc::ptr_eq(&module.file, &global_symbol.module.file)
+fn check_global_symbol_visibility(
+    module: &Module,
+    global_symbol: &GlobalSymbol,
+    span: Span,
+) -> bool {
+    if global_symbol
+        .visibility
+        .is_visible(&global_symbol.module, module)
     {
         return true;
     }
 
-    let path = visualize_global_symbol_path(&global_symbol);
+    let path = visualize_global_symbol_path(global_symbol);
     module.diagnostics.error_sub(
         exc_diagnostic::error_codes::SYMBOL_IS_NOT_VISIBLE,
-        global_symbol.kind.identifier().span,
+        span,
         format!("the symbol `{}` is not visible from this module", path),
-        vec![{
-            module.diagnostics.sub_hint_simple(format!(
-                "consider making the symbol public, or accessing it in same file"
-            ))
-        }],
+        vec![visibility_hint(module, &global_symbol.visibility, "symbol")],
     );
 
     false
 }
 
+fn visibility_hint(module: &Module, visibility: &Visibility, item: &str) -> SubDiagnostics {
+    match visibility {
+        Visibility::Restricted(path) => module.diagnostics.sub_hint_simple(format!(
+            "the {} is visible only in the module `{}`",
+            item,
+            visualize_module_path(path)
+        )),
+        _ => module.diagnostics.sub_hint_simple(format!(
+            "consider making the {} public, or accessing it in same file",
+            item
+        )),
+    }
+}
+
 fn build_glob_import_blocklist(
     global_symbol_registry: &GlobalSymbolRegistry,
     module: &Module,
@@ -589,20 +641,20 @@ fn build_glob_import_blocklist(
     let mut count_map = HashMap::<Symbol, usize>::new();
 
     for glob_redirect in glob_redirects {
-        if let Some(global_symbols) =
-            global_symbol_registry.non_module_symbols(&glob_redirect.target_module)
+        for global_symbol in glob_redirect
+            .target
+            .non_module_symbols(global_symbol_registry)
         {
-            for global_symbol in global_symbols {
-                if global_symbol.visibility == Visibility::Private
-                    && !Arc::ptr_eq(&module.file, &global_symbol.module.file)
-                {
-                    continue;
-                }
-
-                *count_map
-                    .entry(global_symbol.kind.identifier().symbol)
-                    .or_default() += 1;
+            if !global_symbol
+                .visibility
+                .is_visible(&global_symbol.module, module)
+            {
+                continue;
             }
+
+            *count_map
+                .entry(global_symbol.kind.identifier().symbol)
+                .or_default() += 1;
         }
     }
 
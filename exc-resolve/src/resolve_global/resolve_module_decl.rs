@@ -1,4 +1,4 @@
-use crate::{Module, ModuleRegistry, SourceFileResolver};
+use crate::{Module, ModuleASTKind, ModuleRegistry, SourceFileResolver};
 use async_recursion::async_recursion;
 use exc_parse::{ASTModuleDecl, ASTModuleItemKind};
 use exc_symbol::Symbol;
@@ -14,11 +14,21 @@ pub async fn resolve_module_decl(
         let ast = match &item.kind {
             ASTModuleItemKind::Use(_) => continue,
             ASTModuleItemKind::AliasDef(_) => continue,
+            ASTModuleItemKind::ConstDef(_) => continue,
+            ASTModuleItemKind::StaticDef(_) => continue,
             ASTModuleItemKind::ModuleDecl(ast) => ast,
-            ASTModuleItemKind::ModuleDef(_) => continue,
+            ASTModuleItemKind::ModuleDef(ast) => {
+                // inline modules may declare file modules too; duplicated ones are not registered
+                if let Some(submodule) = module_registry.get_module_by_id(ast.id).cloned() {
+                    resolve_module_decl(file_resolver, module_registry, &submodule).await;
+                }
+
+                continue;
+            }
             ASTModuleItemKind::ExternBlock(_) => continue,
             ASTModuleItemKind::FnDef(_) => continue,
             ASTModuleItemKind::StructDef(_) => continue,
+            ASTModuleItemKind::EnumDef(_) => continue,
             ASTModuleItemKind::InterfaceDef(_) => continue,
             ASTModuleItemKind::ImplBlock(_) => continue,
         };
@@ -34,8 +44,14 @@ pub async fn resolve_single_module_decl(
     ast: &ASTModuleDecl,
 ) {
     let mut path = module.path.clone();
-    debug_assert!(!path.is_empty());
-    path.pop();
+
+    // the modules declared in a file are placed next to it,
+    // while the ones declared in an inline module are placed inside of it
+    if let ModuleASTKind::Module(_) = &module.ast {
+        debug_assert!(!path.is_empty());
+        path.pop();
+    }
+
     path.push(ast.identifier.symbol);
 
     let fs_path = make_fs_path(&path);
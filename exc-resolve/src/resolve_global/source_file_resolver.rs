@@ -72,7 +72,7 @@ impl SourceFileResolver {
             self.source_map
                 .add_source_file(&content, file_name, Some(relative_path.to_owned()));
         let diagnostics = DiagnosticsSender::new(file.clone(), self.diagnostics_receiver.sender());
-        let token_stream = token_iter(&file);
+        let token_stream = token_iter(&file, &diagnostics);
 
         let mut path = Vec::new();
 
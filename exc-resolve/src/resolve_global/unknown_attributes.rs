@@ -0,0 +1,107 @@
+use crate::Module;
+use exc_diagnostic::warning_codes;
+use exc_parse::{
+    ASTAttribute, ASTExternBlockItemKind, ASTImplBlock, ASTModuleItemKind, ASTStructDef,
+    PunctuatedItem, ATTRIBUTE_ALLOW, ATTRIBUTE_DENY, ATTRIBUTE_DEPRECATED, ATTRIBUTE_INLINE,
+    ATTRIBUTE_LINK_NAME, ATTRIBUTE_TEST, ATTRIBUTE_WARN,
+};
+
+/// Warns about attributes the compiler doesn't know, e.g. a misspelled `@inlin`,
+/// and about known ones on items they have no effect on, e.g. `@link_name` on a `fn`.
+/// The attributes of the items in submodules are checked along with the submodules themselves.
+pub fn check_unknown_attributes(module: &Module) {
+    for item in module.ast.items() {
+        check_attributes(module, &item.attributes, false);
+
+        match &item.kind {
+            ASTModuleItemKind::Use(_) => {}
+            ASTModuleItemKind::AliasDef(_) => {}
+            ASTModuleItemKind::ConstDef(_) => {}
+            ASTModuleItemKind::StaticDef(_) => {}
+            ASTModuleItemKind::ModuleDecl(_) => {}
+            ASTModuleItemKind::ModuleDef(_) => {}
+            ASTModuleItemKind::ExternBlock(ast) => {
+                for item in &ast.items {
+                    let is_prototype = matches!(item.kind, ASTExternBlockItemKind::PrototypeDef(_));
+                    check_attributes(module, &item.attributes, is_prototype);
+
+                    match &item.kind {
+                        ASTExternBlockItemKind::PrototypeDef(_) => {}
+                        ASTExternBlockItemKind::FnDef(_) => {}
+                        ASTExternBlockItemKind::StructDef(ast) => {
+                            check_struct_def_attributes(module, ast)
+                        }
+                        ASTExternBlockItemKind::ImplBlock(ast) => {
+                            check_impl_block_attributes(module, ast)
+                        }
+                    }
+                }
+            }
+            ASTModuleItemKind::FnDef(_) => {}
+            ASTModuleItemKind::StructDef(ast) => check_struct_def_attributes(module, ast),
+            ASTModuleItemKind::EnumDef(_) => {}
+            ASTModuleItemKind::InterfaceDef(_) => {}
+            ASTModuleItemKind::ImplBlock(ast) => check_impl_block_attributes(module, ast),
+        }
+    }
+}
+
+fn check_struct_def_attributes(module: &Module, ast: &ASTStructDef) {
+    for field in &ast.fields.items {
+        let field = match field {
+            PunctuatedItem::Punctuated { item, .. } => item,
+            PunctuatedItem::NotPunctuated { item } => item,
+        };
+
+        check_attributes(module, &field.attributes, false);
+    }
+}
+
+fn check_impl_block_attributes(module: &Module, ast: &ASTImplBlock) {
+    for item in &ast.items {
+        check_attributes(module, &item.attributes, false);
+    }
+}
+
+/// `@link_name` only applies to prototypes, since they're the only items bound by name.
+fn check_attributes(module: &Module, attributes: &[ASTAttribute], is_prototype: bool) {
+    let known = [
+        *ATTRIBUTE_INLINE,
+        *ATTRIBUTE_TEST,
+        *ATTRIBUTE_DEPRECATED,
+        *ATTRIBUTE_LINK_NAME,
+        *ATTRIBUTE_ALLOW,
+        *ATTRIBUTE_WARN,
+        *ATTRIBUTE_DENY,
+    ];
+
+    for attribute in attributes {
+        if attribute.identifier.symbol == *ATTRIBUTE_LINK_NAME && !is_prototype {
+            module.diagnostics.warning_sub(
+                warning_codes::MISPLACED_ATTRIBUTE,
+                attribute.identifier.span,
+                format!(
+                    "the attribute {} only applies to prototypes in extern blocks",
+                    attribute.identifier.symbol
+                ),
+                vec![module
+                    .diagnostics
+                    .sub_hint_simple("the attribute is ignored; consider removing it".to_owned())],
+            );
+            continue;
+        }
+
+        if known.contains(&attribute.identifier.symbol) {
+            continue;
+        }
+
+        module.diagnostics.warning_sub(
+            warning_codes::UNKNOWN_ATTRIBUTE,
+            attribute.identifier.span,
+            format!("the attribute {} is unknown", attribute.identifier.symbol),
+            vec![module
+                .diagnostics
+                .sub_hint_simple("the attribute is ignored; consider removing it".to_owned())],
+        );
+    }
+}
@@ -1,5 +1,128 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+use crate::{visualize_module_path, Module};
+use exc_parse::{ASTUsePathPrefixSegmentKind, ASTVisibility, ASTVisibilityScopeKind, Id};
+use exc_span::Span;
+use exc_symbol::Symbol;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Visibility {
     Public,
+    /// visible in the module of the path and its descendants, e.g. `pub(super)`;
+    /// the empty path is the crate, which contains every module
+    Restricted(Vec<Symbol>),
     Private,
 }
+
+#[derive(Debug, Clone, Copy)]
+pub enum VisibilityError {
+    /// `super` is used in a module at root level
+    NoSuper(Id),
+    /// the path of `pub(in path)` doesn't refer to the module or its ancestors
+    NotAncestor(Span),
+}
+
+impl Visibility {
+    /// Makes the visibility of an item defined in the module of the path.
+    ///
+    /// `pub(module)` is visible in the whole crate.
+    /// The path of `pub(in path)` is relative to the module, like the prefix of `use`.
+    pub fn from_ast(
+        module_path: &[Symbol],
+        ast: Option<&ASTVisibility>,
+    ) -> Result<Self, VisibilityError> {
+        let scope = match ast {
+            Some(ast) => match &ast.scope {
+                Some(scope) => scope,
+                None => return Ok(Self::Public),
+            },
+            None => return Ok(Self::Private),
+        };
+
+        match &scope.kind {
+            ASTVisibilityScopeKind::Super_(id) => {
+                if module_path.len() <= 1 {
+                    return Err(VisibilityError::NoSuper(*id));
+                }
+
+                Ok(Self::Restricted(
+                    module_path[..module_path.len() - 1].to_vec(),
+                ))
+            }
+            ASTVisibilityScopeKind::Module(_) => Ok(Self::Restricted(Vec::new())),
+            ASTVisibilityScopeKind::In(ast) => {
+                let mut path = module_path.to_vec();
+
+                for segment in &ast.path.items {
+                    match segment.clone().into_item() {
+                        ASTUsePathPrefixSegmentKind::Self_(_) => {}
+                        ASTUsePathPrefixSegmentKind::Super_(id) => {
+                            if path.len() <= 1 {
+                                return Err(VisibilityError::NoSuper(id));
+                            }
+
+                            path.pop();
+                        }
+                        ASTUsePathPrefixSegmentKind::Identifier(id) => {
+                            path.push(id.symbol);
+                        }
+                    }
+                }
+
+                if !module_path.starts_with(&path) {
+                    return Err(VisibilityError::NotAncestor(ast.span));
+                }
+
+                Ok(Self::Restricted(path))
+            }
+        }
+    }
+
+    /// Checks whether an item of the `owner` module with this visibility can be accessed from the `module`.
+    /// Items are always visible in the same file.
+    pub fn is_visible(&self, owner: &Module, module: &Module) -> bool {
+        if Arc::ptr_eq(&owner.file, &module.file) {
+            return true;
+        }
+
+        match self {
+            Self::Public => true,
+            Self::Restricted(path) => module.path.starts_with(path),
+            Self::Private => false,
+        }
+    }
+}
+
+/// Makes the visibility of an item defined in the module, reporting the errors.
+/// The item falls back to private on errors.
+pub fn resolve_visibility(module: &Module, ast: Option<&ASTVisibility>) -> Visibility {
+    match Visibility::from_ast(&module.path, ast) {
+        Ok(visibility) => visibility,
+        Err(VisibilityError::NoSuper(id)) => {
+            module.diagnostics.error(
+                exc_diagnostic::error_codes::MODULE_HAS_NO_SUPER,
+                id.span,
+                format!(
+                    "{} is not allowed here; the module `{}` is already at root level",
+                    id.symbol,
+                    visualize_module_path(&module.path)
+                ),
+            );
+            Visibility::Private
+        }
+        Err(VisibilityError::NotAncestor(span)) => {
+            module.diagnostics.error_sub(
+                exc_diagnostic::error_codes::INVALID_VISIBILITY_PATH,
+                span,
+                format!(
+                    "the path does not refer to the module `{}` or its ancestors",
+                    visualize_module_path(&module.path)
+                ),
+                vec![module.diagnostics.sub_hint_simple(
+                    "`pub(in path)` can only make the item visible in an enclosing module"
+                        .to_owned(),
+                )],
+            );
+            Visibility::Private
+        }
+    }
+}
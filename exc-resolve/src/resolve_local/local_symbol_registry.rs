@@ -1,10 +1,11 @@
 use crate::{
     visualize_module_path, GlobalSymbol, GlobalSymbolKind, GlobalSymbolRegistry, Module,
-    ModuleRegistry,
+    ModuleRegistry, Namespace,
 };
 use exc_diagnostic::error_codes;
 use exc_parse::{
-    ASTExpr, ASTExprKind, ASTFnDef, ASTStmtBlock, ASTStmtKind, Id, NodeId, PunctuatedItem,
+    ASTExpr, ASTExprCall, ASTExprKind, ASTExprMatchArmBody, ASTFnDef, ASTPath, ASTPattern,
+    ASTPatternKind, ASTStmtBlock, ASTStmtKind, Id, NodeId, PunctuatedItem,
 };
 use exc_symbol::Symbol;
 use std::{
@@ -100,6 +101,27 @@ impl LocalSymbolVariable {
     }
 }
 
+/// A closure in a function, with the variables of the enclosing scopes that it refers to.
+#[derive(Debug, Clone)]
+pub struct LocalClosure {
+    pub id: NodeId,
+    pub function: NodeId,
+    /// the scope of the parameters; the variables of the scopes before it are captured
+    pub scope: ScopeIndex,
+    pub captures: Vec<LocalSymbol>,
+}
+
+impl LocalClosure {
+    pub fn new(id: NodeId, function: NodeId, scope: ScopeIndex) -> Self {
+        Self {
+            id,
+            function,
+            scope,
+            captures: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ScopeTable {
     pub function: NodeId,
@@ -199,9 +221,29 @@ impl Scope {
     }
 }
 
+/// The scope that a pattern binds its names into,
+/// along with the names already bound by the same pattern to report the duplicated ones.
+struct PatternBindings {
+    scope_index: ScopeIndex,
+    bindings: HashMap<Symbol, Id>,
+}
+
+impl PatternBindings {
+    fn new(scope_index: ScopeIndex) -> Self {
+        Self {
+            scope_index,
+            bindings: HashMap::new(),
+        }
+    }
+}
+
 #[derive(Default, Debug)]
 pub struct LocalSymbolRegistry {
     symbols: HashMap<NodeId, HashMap<NodeId, LocalSymbol>>,
+    closures: HashMap<NodeId, LocalClosure>,
+    closure_stack: Vec<NodeId>,
+    /// labels of the loops enclosing the statement being registered, innermost last
+    loop_stack: Vec<Option<Symbol>>,
 }
 
 impl LocalSymbolRegistry {
@@ -209,6 +251,14 @@ impl LocalSymbolRegistry {
         Default::default()
     }
 
+    pub fn closures(&self) -> impl Iterator<Item = &LocalClosure> {
+        self.closures.values()
+    }
+
+    pub fn get_closure(&self, id: NodeId) -> Option<&LocalClosure> {
+        self.closures.get(&id)
+    }
+
     pub fn register(
         &mut self,
         module_registry: &ModuleRegistry,
@@ -222,21 +272,60 @@ impl LocalSymbolRegistry {
             };
 
             for symbol in symbols {
-                let ast = match &symbol.kind {
-                    GlobalSymbolKind::ModuleDecl(_) => continue,
-                    GlobalSymbolKind::ModuleDef(_) => continue,
-                    GlobalSymbolKind::Alias(_) => continue,
-                    GlobalSymbolKind::Prototype(_) => continue,
-                    GlobalSymbolKind::Fn(ast) => ast,
-                    GlobalSymbolKind::Struct(_) => continue,
-                    GlobalSymbolKind::Interface(_) => continue,
-                };
-
-                self.register_function(module_registry, global_symbol_registry, module, ast);
+                match &symbol.kind {
+                    GlobalSymbolKind::ModuleDecl(_) => {}
+                    GlobalSymbolKind::ModuleDef(_) => {}
+                    GlobalSymbolKind::Alias(_) => {}
+                    GlobalSymbolKind::Const(ast) => self.register_global_value(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        ast.id,
+                        &ast.expr,
+                    ),
+                    GlobalSymbolKind::Static(ast) => self.register_global_value(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        ast.id,
+                        &ast.expr,
+                    ),
+                    GlobalSymbolKind::Prototype(_) => {}
+                    GlobalSymbolKind::Fn(ast) => {
+                        self.register_function(module_registry, global_symbol_registry, module, ast)
+                    }
+                    GlobalSymbolKind::Struct(_) => {}
+                    GlobalSymbolKind::Enum(_) => {}
+                    GlobalSymbolKind::EnumVariant(_) => {}
+                    GlobalSymbolKind::Interface(_) => {}
+                }
             }
         }
     }
 
+    /// Registers the symbols of the initializer of a `const` or `static`.
+    /// The initializer is resolved like a function body without parameters, keyed by the id of the definition.
+    fn register_global_value(
+        &mut self,
+        module_registry: &ModuleRegistry,
+        global_symbol_registry: &GlobalSymbolRegistry,
+        module: &Arc<Module>,
+        id: NodeId,
+        expr: &ASTExpr,
+    ) {
+        let mut scope_table = ScopeTable::new(id);
+        let scope_index = scope_table.new_scope(None);
+
+        self.register_function_expr(
+            module_registry,
+            global_symbol_registry,
+            module,
+            expr,
+            scope_index,
+            &mut scope_table,
+        );
+    }
+
     fn register_function(
         &mut self,
         module_registry: &ModuleRegistry,
@@ -246,12 +335,33 @@ impl LocalSymbolRegistry {
     ) {
         let mut scope_table = ScopeTable::new(ast.id);
 
+        // the parameters live in their own scope, so that the body can shadow them
+        let param_scope_index = scope_table.new_scope(None);
+        let mut bindings = PatternBindings::new(param_scope_index);
+
+        for param in &ast.params.items {
+            let param = match param {
+                PunctuatedItem::Punctuated { item, .. } => item,
+                PunctuatedItem::NotPunctuated { item } => item,
+            };
+
+            self.register_function_pattern(
+                module_registry,
+                global_symbol_registry,
+                module,
+                &param.pattern,
+                &mut scope_table,
+                &mut bindings,
+            );
+            self.check_irrefutable_pattern(module, ast.id, &param.pattern);
+        }
+
         self.register_function_stmt_block(
             module_registry,
             global_symbol_registry,
             module,
             &ast.stmt_block,
-            None,
+            Some(param_scope_index),
             &mut scope_table,
         );
 
@@ -271,19 +381,8 @@ impl LocalSymbolRegistry {
 
         for stmt in &ast.stmts {
             match &stmt.kind {
-                ASTStmtKind::Block(ast) => {
-                    self.register_function_stmt_block(
-                        module_registry,
-                        global_symbol_registry,
-                        module,
-                        ast,
-                        Some(scope_index),
-                        scope_table,
-                    );
-                }
                 ASTStmtKind::Let(ast) => {
-                    scope_table.new_variable(ast.id, ast.identifier.symbol, scope_index);
-
+                    // the initializer can't see the variables it's about to bind
                     if let Some(ast) = &ast.expr {
                         self.register_function_expr(
                             module_registry,
@@ -294,68 +393,40 @@ impl LocalSymbolRegistry {
                             scope_table,
                         );
                     }
-                }
-                ASTStmtKind::If(ast) => {
-                    self.register_function_expr(
+
+                    self.register_function_pattern(
                         module_registry,
                         global_symbol_registry,
                         module,
-                        &ast.expr,
-                        scope_index,
+                        &ast.pattern,
                         scope_table,
+                        &mut PatternBindings::new(scope_index),
                     );
-
-                    self.register_function_stmt_block(
+                    self.check_irrefutable_pattern(module, scope_table.function, &ast.pattern);
+                }
+                ASTStmtKind::While(ast) => {
+                    self.register_function_expr(
                         module_registry,
                         global_symbol_registry,
                         module,
-                        &ast.stmt_block,
-                        parent,
+                        &ast.expr,
+                        scope_index,
                         scope_table,
                     );
 
-                    for ast in &ast.else_ifs {
-                        self.register_function_expr(
-                            module_registry,
-                            global_symbol_registry,
-                            module,
-                            &ast.expr,
-                            scope_index,
-                            scope_table,
-                        );
-
-                        self.register_function_stmt_block(
-                            module_registry,
-                            global_symbol_registry,
-                            module,
-                            &ast.stmt_block,
-                            parent,
-                            scope_table,
-                        );
-                    }
-
-                    if let Some(ast) = &ast.else_ {
-                        self.register_function_stmt_block(
-                            module_registry,
-                            global_symbol_registry,
-                            module,
-                            &ast.stmt_block,
-                            parent,
-                            scope_table,
-                        );
-                    }
-                }
-                ASTStmtKind::Loop(ast) => {
+                    self.loop_stack
+                        .push(ast.label.as_ref().map(|label| label.label.symbol));
                     self.register_function_stmt_block(
                         module_registry,
                         global_symbol_registry,
                         module,
                         &ast.stmt_block,
-                        parent,
+                        Some(scope_index),
                         scope_table,
                     );
+                    self.loop_stack.pop();
                 }
-                ASTStmtKind::While(ast) => {
+                ASTStmtKind::For(ast) => {
                     self.register_function_expr(
                         module_registry,
                         global_symbol_registry,
@@ -365,17 +436,39 @@ impl LocalSymbolRegistry {
                         scope_table,
                     );
 
+                    // the loop variable lives in its own scope, visible only in the body
+                    let loop_scope_index = scope_table.new_scope(Some(scope_index));
+                    scope_table.new_variable(ast.id, ast.identifier.symbol, loop_scope_index);
+
+                    self.loop_stack
+                        .push(ast.label.as_ref().map(|label| label.label.symbol));
                     self.register_function_stmt_block(
                         module_registry,
                         global_symbol_registry,
                         module,
                         &ast.stmt_block,
-                        parent,
+                        Some(loop_scope_index),
                         scope_table,
                     );
+                    self.loop_stack.pop();
+                }
+                ASTStmtKind::Break(ast) => {
+                    self.check_loop_target(module, &ast.keyword_break, ast.label);
+
+                    if let Some(ast) = &ast.expr {
+                        self.register_function_expr(
+                            module_registry,
+                            global_symbol_registry,
+                            module,
+                            ast,
+                            scope_index,
+                            scope_table,
+                        );
+                    }
+                }
+                ASTStmtKind::Continue(ast) => {
+                    self.check_loop_target(module, &ast.keyword_continue, ast.label);
                 }
-                ASTStmtKind::Break(_) => continue,
-                ASTStmtKind::Continue(_) => continue,
                 ASTStmtKind::Return(ast) => {
                     if let Some(ast) = &ast.expr {
                         self.register_function_expr(
@@ -431,6 +524,29 @@ impl LocalSymbolRegistry {
         scope_table: &mut ScopeTable,
     ) {
         match &ast.kind {
+            ASTExprKind::Range(ast) => {
+                if let Some(start) = &ast.start {
+                    self.register_function_expr(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        start,
+                        scope_index,
+                        scope_table,
+                    );
+                }
+
+                if let Some(end) = &ast.end {
+                    self.register_function_expr(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        end,
+                        scope_index,
+                        scope_table,
+                    );
+                }
+            }
             ASTExprKind::Binary(ast) => {
                 self.register_function_expr(
                     module_registry,
@@ -480,6 +596,8 @@ impl LocalSymbolRegistry {
                     scope_table,
                 );
 
+                self.check_argument_count(module, scope_table.function, ast);
+
                 for ast in &ast.args.items {
                     match &ast {
                         PunctuatedItem::Punctuated { item, .. } => {
@@ -517,6 +635,25 @@ impl LocalSymbolRegistry {
                     scope_table,
                 );
             }
+            ASTExprKind::Index(ast) => {
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.expr,
+                    scope_index,
+                    scope_table,
+                );
+
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.index,
+                    scope_index,
+                    scope_table,
+                );
+            }
             ASTExprKind::Paren(ast) => {
                 self.register_function_expr(
                     module_registry,
@@ -527,6 +664,32 @@ impl LocalSymbolRegistry {
                     scope_table,
                 );
             }
+            ASTExprKind::Tuple(ast) => {
+                for ast in &ast.elements.items {
+                    match &ast {
+                        PunctuatedItem::Punctuated { item, .. } => {
+                            self.register_function_expr(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                item,
+                                scope_index,
+                                scope_table,
+                            );
+                        }
+                        PunctuatedItem::NotPunctuated { item } => {
+                            self.register_function_expr(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                item,
+                                scope_index,
+                                scope_table,
+                            );
+                        }
+                    }
+                }
+            }
             ASTExprKind::Path(ast) => {
                 if ast.path.segments.items.len() == 1 {
                     let segment = match ast.path.segments.items.first().unwrap() {
@@ -546,6 +709,8 @@ impl LocalSymbolRegistry {
                                 segment.identifier,
                             );
 
+                            self.capture_variable(&local_symbol, &scope_table[variable_index]);
+
                             self.symbols
                                 .entry(scope_table.function)
                                 .or_default()
@@ -556,93 +721,240 @@ impl LocalSymbolRegistry {
                     }
                 }
 
-                let (last, prefix) = ast.path.segments.items.split_last().unwrap();
-                let mut target_module = module;
+                self.register_function_path(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    ast.id,
+                    &ast.path,
+                    scope_table,
+                );
+            }
+            ASTExprKind::Literal(_) => {}
+            ASTExprKind::Block(ast) => {
+                self.register_function_stmt_block(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    ast,
+                    Some(scope_index),
+                    scope_table,
+                );
+            }
+            ASTExprKind::If(ast) => {
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.expr,
+                    scope_index,
+                    scope_table,
+                );
+
+                self.register_function_stmt_block(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.stmt_block,
+                    Some(scope_index),
+                    scope_table,
+                );
+
+                for ast in &ast.else_ifs {
+                    self.register_function_expr(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &ast.expr,
+                        scope_index,
+                        scope_table,
+                    );
+
+                    self.register_function_stmt_block(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &ast.stmt_block,
+                        Some(scope_index),
+                        scope_table,
+                    );
+                }
+
+                if let Some(ast) = &ast.else_ {
+                    self.register_function_stmt_block(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &ast.stmt_block,
+                        Some(scope_index),
+                        scope_table,
+                    );
+                }
+            }
+            ASTExprKind::Loop(ast) => {
+                self.loop_stack
+                    .push(ast.label.as_ref().map(|label| label.label.symbol));
+                self.register_function_stmt_block(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.stmt_block,
+                    Some(scope_index),
+                    scope_table,
+                );
+                self.loop_stack.pop();
+            }
+            ASTExprKind::Match(ast) => {
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.expr,
+                    scope_index,
+                    scope_table,
+                );
 
-                for segment in prefix {
-                    let segment = match segment {
+                for arm in &ast.arms.items {
+                    let arm = match arm {
                         PunctuatedItem::Punctuated { item, .. } => item,
                         PunctuatedItem::NotPunctuated { item } => item,
                     };
 
-                    if let Some(generic) = &segment.generic {
-                        module.diagnostics.error_sub(
-                            error_codes::GENERIC_ARGUMENT_ON_PATH_PREFIX_NOT_ALLOWED,
-                            generic.span,
-                            format!("generic arguments are not allowed on prefix"),
-                            vec![{
-                                module.diagnostics.sub_hint(
-                                    generic.span,
-                                    format!("consider removing generic arguments"),
-                                )
-                            }],
-                        );
-                    }
+                    // the bindings of each arm live in its own scope, visible only in the arm
+                    let arm_scope_index = scope_table.new_scope(Some(scope_index));
 
-                    let module = match global_symbol_registry
-                        .get_module_symbol(&target_module, segment.identifier.symbol)
-                    {
-                        Some(symbol) => match &symbol.kind {
-                            GlobalSymbolKind::ModuleDecl(ast) => {
-                                module_registry.get_module_by_id(ast.id).unwrap()
-                            }
-                            GlobalSymbolKind::ModuleDef(ast) => {
-                                module_registry.get_module_by_id(ast.id).unwrap()
-                            }
-                            GlobalSymbolKind::Alias(_) => unreachable!(),
-                            GlobalSymbolKind::Prototype(_) => unreachable!(),
-                            GlobalSymbolKind::Fn(_) => unreachable!(),
-                            GlobalSymbolKind::Struct(_) => unreachable!(),
-                            GlobalSymbolKind::Interface(_) => unreachable!(),
-                        },
-                        None => {
-                            module.diagnostics.error(
-                                error_codes::PATH_PREFIX_IS_NOT_RESOLVED,
-                                segment.identifier.span,
-                                format!("the prefix {} is not resolved; it is not a module or not exists", segment.identifier.symbol),
+                    self.register_function_pattern(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &arm.pattern,
+                        scope_table,
+                        &mut PatternBindings::new(arm_scope_index),
+                    );
+
+                    match &arm.body {
+                        ASTExprMatchArmBody::Block(ast) => {
+                            self.register_function_stmt_block(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                ast,
+                                Some(arm_scope_index),
+                                scope_table,
                             );
-                            return;
                         }
+                        ASTExprMatchArmBody::Expr(ast) => {
+                            self.register_function_expr(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                ast,
+                                arm_scope_index,
+                                scope_table,
+                            );
+                        }
+                    }
+                }
+            }
+            ASTExprKind::Closure(ast) => {
+                // the closure can see the variables of the enclosing scopes
+                let param_scope_index = scope_table.new_scope(Some(scope_index));
+                let mut bindings = PatternBindings::new(param_scope_index);
+
+                self.closures.insert(
+                    ast.id,
+                    LocalClosure::new(ast.id, scope_table.function, param_scope_index),
+                );
+                self.closure_stack.push(ast.id);
+                // `break` and `continue` can't leave the closure
+                let loop_stack = std::mem::take(&mut self.loop_stack);
+
+                for param in &ast.params.items {
+                    let param = match param {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
                     };
 
-                    target_module = module;
+                    self.register_function_pattern(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &param.pattern,
+                        scope_table,
+                        &mut bindings,
+                    );
+                    self.check_irrefutable_pattern(module, scope_table.function, &param.pattern);
                 }
 
-                let last = match last {
-                    PunctuatedItem::Punctuated { item, .. } => item,
-                    PunctuatedItem::NotPunctuated { item } => item,
-                };
-
-                match global_symbol_registry
-                    .get_non_module_symbol(target_module, last.identifier.symbol)
-                {
-                    Some(symbol) => {
-                        let local_symbol = LocalSymbol::new(
-                            module.clone(),
-                            scope_table.function,
-                            LocalSymbolKind::Global(symbol.clone()),
-                            last.identifier,
-                        );
+                self.register_function_stmt_block(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.stmt_block,
+                    Some(param_scope_index),
+                    scope_table,
+                );
 
-                        self.symbols
-                            .entry(scope_table.function)
-                            .or_default()
-                            .insert(ast.id, local_symbol);
-                    }
-                    None => {
-                        module.diagnostics.error(
-                            error_codes::SYMBOL_NOT_FOUND,
-                            last.identifier.span,
-                            format!(
-                                "the symbol {} is not found in the module `{}`",
-                                last.identifier.symbol,
-                                visualize_module_path(&target_module.path)
-                            ),
-                        );
+                self.loop_stack = loop_stack;
+                self.closure_stack.pop();
+            }
+            ASTExprKind::InterpolatedString(ast) => {
+                for ast in &ast.interpolations {
+                    self.register_function_expr(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        &ast.expr,
+                        scope_index,
+                        scope_table,
+                    );
+                }
+            }
+            ASTExprKind::ArrayLiteral(ast) => {
+                for ast in &ast.elements.items {
+                    match &ast {
+                        PunctuatedItem::Punctuated { item, .. } => {
+                            self.register_function_expr(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                item,
+                                scope_index,
+                                scope_table,
+                            );
+                        }
+                        PunctuatedItem::NotPunctuated { item } => {
+                            self.register_function_expr(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                item,
+                                scope_index,
+                                scope_table,
+                            );
+                        }
                     }
                 }
             }
-            ASTExprKind::Literal(_) => {}
+            ASTExprKind::ArrayRepeat(ast) => {
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.expr,
+                    scope_index,
+                    scope_table,
+                );
+
+                self.register_function_expr(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    &ast.count,
+                    scope_index,
+                    scope_table,
+                );
+            }
             ASTExprKind::StructLiteral(ast) => {
                 for ast in &ast.fields.items {
                     match &ast {
@@ -671,4 +983,440 @@ impl LocalSymbolRegistry {
             }
         }
     }
+
+    /// Registers every name bound by the pattern as a variable of the scope of the `bindings`.
+    fn register_function_pattern(
+        &mut self,
+        module_registry: &ModuleRegistry,
+        global_symbol_registry: &GlobalSymbolRegistry,
+        module: &Arc<Module>,
+        ast: &ASTPattern,
+        scope_table: &mut ScopeTable,
+        bindings: &mut PatternBindings,
+    ) {
+        match &ast.kind {
+            ASTPatternKind::Wildcard(_) => {}
+            ASTPatternKind::Binding(ast) => {
+                register_binding(module, ast.id, ast.identifier, scope_table, bindings);
+            }
+            ASTPatternKind::Literal(_) => {}
+            ASTPatternKind::Range(_) => {}
+            ASTPatternKind::Path(ast) => {
+                self.register_function_path(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    ast.id,
+                    &ast.path,
+                    scope_table,
+                );
+            }
+            ASTPatternKind::Struct(ast) => {
+                self.register_function_path(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    ast.id,
+                    &ast.path,
+                    scope_table,
+                );
+
+                for ast in &ast.fields.items {
+                    let ast = match ast {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    };
+
+                    match &ast.pattern {
+                        Some(pattern) => {
+                            self.register_function_pattern(
+                                module_registry,
+                                global_symbol_registry,
+                                module,
+                                &pattern.pattern,
+                                scope_table,
+                                bindings,
+                            );
+                        }
+                        None => {
+                            // shorthand field, e.g. `Point { x }`, binds the field name itself
+                            register_binding(module, ast.id, ast.identifier, scope_table, bindings);
+                        }
+                    }
+                }
+            }
+            ASTPatternKind::TupleStruct(ast) => {
+                self.register_function_path(
+                    module_registry,
+                    global_symbol_registry,
+                    module,
+                    ast.id,
+                    &ast.path,
+                    scope_table,
+                );
+
+                for ast in &ast.fields.items {
+                    let ast = match ast {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    };
+
+                    self.register_function_pattern(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        ast,
+                        scope_table,
+                        bindings,
+                    );
+                }
+            }
+            ASTPatternKind::Tuple(ast) => {
+                for ast in &ast.items.items {
+                    let ast = match ast {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    };
+
+                    self.register_function_pattern(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        ast,
+                        scope_table,
+                        bindings,
+                    );
+                }
+            }
+            ASTPatternKind::Array(ast) => {
+                for ast in &ast.items.items {
+                    let ast = match ast {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    };
+
+                    self.register_function_pattern(
+                        module_registry,
+                        global_symbol_registry,
+                        module,
+                        ast,
+                        scope_table,
+                        bindings,
+                    );
+                }
+            }
+        }
+    }
+
+    /// Reports a call whose arguments don't fit the parameters of the callee.
+    /// Only the calls of functions and prototypes are checked; the arguments after the fixed parameters of a variadic prototype are its variadic arguments.
+    /// The callee must be registered before.
+    fn check_argument_count(&self, module: &Arc<Module>, function: NodeId, ast: &ASTExprCall) {
+        let id = match &ast.callee.expr.kind {
+            ASTExprKind::Path(ast) => ast.id,
+            _ => return,
+        };
+        let symbol = match self
+            .symbols
+            .get(&function)
+            .and_then(|symbols| symbols.get(&id))
+        {
+            Some(LocalSymbol {
+                kind: LocalSymbolKind::Global(symbol),
+                ..
+            }) => symbol,
+            _ => return,
+        };
+        let (params, is_variadic) = match &symbol.kind {
+            GlobalSymbolKind::Fn(ast) => (ast.params.items.len(), false),
+            GlobalSymbolKind::Prototype(prototype) => (
+                prototype.prototype_def.params.items.len(),
+                prototype.prototype_def.is_variadic(),
+            ),
+            _ => return,
+        };
+        let args = ast.args.items.len();
+
+        if args == params || (is_variadic && params < args) {
+            return;
+        }
+
+        module.diagnostics.error(
+            error_codes::ARGUMENT_COUNT_MISMATCH,
+            ast.span,
+            format!(
+                "the function {} takes {}{} argument{} but {} {} given",
+                symbol.identifier.symbol,
+                if is_variadic { "at least " } else { "" },
+                params,
+                if params == 1 { "" } else { "s" },
+                args,
+                if args == 1 { "was" } else { "were" }
+            ),
+        );
+    }
+
+    /// Reports `break` and `continue` outside of a loop, and labels that don't refer to an enclosing loop.
+    fn check_loop_target(&self, module: &Arc<Module>, keyword: &Id, label: Option<Id>) {
+        if self.loop_stack.is_empty() {
+            module.diagnostics.error(
+                error_codes::BREAK_OUTSIDE_OF_LOOP,
+                keyword.span,
+                format!("{} outside of a loop", keyword.symbol),
+            );
+            return;
+        }
+
+        let label = match label {
+            Some(label) => label,
+            None => return,
+        };
+
+        if self.loop_stack.contains(&Some(label.symbol)) {
+            return;
+        }
+
+        module.diagnostics.error_sub(
+            error_codes::UNDECLARED_LABEL,
+            label.span,
+            format!("use of undeclared label {}", label.symbol),
+            vec![module.diagnostics.sub_hint(
+                label.span,
+                "a label must refer to an enclosing loop of the same function".to_owned(),
+            )],
+        );
+    }
+
+    /// Reports the parts of the pattern that may not match, e.g. literals or variants of an enum with other variants.
+    /// Used for the patterns of `let` and function parameters, which must always match.
+    /// The paths in the pattern must be registered before.
+    fn check_irrefutable_pattern(&self, module: &Arc<Module>, function: NodeId, ast: &ASTPattern) {
+        let (id, items): (NodeId, Vec<&ASTPattern>) = match &ast.kind {
+            ASTPatternKind::Wildcard(_) => return,
+            ASTPatternKind::Binding(_) => return,
+            ASTPatternKind::Literal(_) | ASTPatternKind::Range(_) => {
+                module.diagnostics.error(
+                    error_codes::REFUTABLE_PATTERN,
+                    ast.span,
+                    "refutable pattern is not allowed here; it may not match".to_owned(),
+                );
+                return;
+            }
+            ASTPatternKind::Path(ast) => (ast.id, Vec::new()),
+            ASTPatternKind::Struct(ast) => (
+                ast.id,
+                ast.fields
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        PunctuatedItem::Punctuated { item, .. } => item.pattern.as_ref(),
+                        PunctuatedItem::NotPunctuated { item } => item.pattern.as_ref(),
+                    })
+                    .map(|pattern| &pattern.pattern)
+                    .collect(),
+            ),
+            ASTPatternKind::TupleStruct(ast) => (
+                ast.id,
+                ast.fields
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    })
+                    .collect(),
+            ),
+            ASTPatternKind::Tuple(ast) => (
+                ast.id,
+                ast.items
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    })
+                    .collect(),
+            ),
+            ASTPatternKind::Array(ast) => (
+                ast.id,
+                ast.items
+                    .items
+                    .iter()
+                    .map(|item| match item {
+                        PunctuatedItem::Punctuated { item, .. } => item,
+                        PunctuatedItem::NotPunctuated { item } => item,
+                    })
+                    .collect(),
+            ),
+        };
+
+        let symbol = self
+            .symbols
+            .get(&function)
+            .and_then(|symbols| symbols.get(&id));
+
+        if let Some(LocalSymbol {
+            kind:
+                LocalSymbolKind::Global(GlobalSymbol {
+                    kind: GlobalSymbolKind::EnumVariant(variant),
+                    ..
+                }),
+            ..
+        }) = symbol
+        {
+            if 1 < variant.enum_def.variants.items.len() {
+                module.diagnostics.error(
+                    error_codes::REFUTABLE_PATTERN,
+                    ast.span,
+                    format!(
+                        "refutable pattern is not allowed here; the enum {} has other variants",
+                        variant.enum_def.identifier.symbol
+                    ),
+                );
+            }
+        }
+
+        for item in items {
+            self.check_irrefutable_pattern(module, function, item);
+        }
+    }
+
+    /// Adds the variable to the captures of the closures being registered, if it's declared outside of them.
+    fn capture_variable(&mut self, local_symbol: &LocalSymbol, variable: &LocalSymbolVariable) {
+        for id in &self.closure_stack {
+            let closure = self.closures.get_mut(id).unwrap();
+
+            if variable.scope < closure.scope && !closure.captures.contains(local_symbol) {
+                closure.captures.push(local_symbol.clone());
+            }
+        }
+    }
+
+    /// Resolves the path into a global symbol, e.g. `module::function` or `Enum::Variant`.
+    fn register_function_path(
+        &mut self,
+        module_registry: &ModuleRegistry,
+        global_symbol_registry: &GlobalSymbolRegistry,
+        module: &Arc<Module>,
+        id: NodeId,
+        path: &ASTPath,
+        scope_table: &ScopeTable,
+    ) {
+        let (last, prefix) = path.segments.items.split_last().unwrap();
+        let mut target = Namespace::Module(module.clone());
+
+        for segment in prefix {
+            let segment = match segment {
+                PunctuatedItem::Punctuated { item, .. } => item,
+                PunctuatedItem::NotPunctuated { item } => item,
+            };
+
+            if let Some(generic) = &segment.generic {
+                module.diagnostics.error_sub(
+                    error_codes::GENERIC_ARGUMENT_ON_PATH_PREFIX_NOT_ALLOWED,
+                    generic.span,
+                    "generic arguments are not allowed on prefix".to_owned(),
+                    vec![{
+                        module.diagnostics.sub_hint(
+                            generic.span,
+                            "consider removing generic arguments".to_owned(),
+                        )
+                    }],
+                );
+            }
+
+            // enums are namespaces of their variants, but variants have nothing inside
+            let namespace = match &target {
+                Namespace::Module(target_module) => global_symbol_registry
+                    .get_module_symbol(target_module, segment.identifier.symbol)
+                    .or_else(|| {
+                        global_symbol_registry
+                            .get_non_module_symbol(target_module, segment.identifier.symbol)
+                    })
+                    .and_then(|symbol| Namespace::from_global_symbol(module_registry, symbol)),
+                Namespace::Enum(_) => None,
+            };
+
+            target = match namespace {
+                Some(namespace) => namespace,
+                None => {
+                    module.diagnostics.error(
+                        error_codes::PATH_PREFIX_IS_NOT_RESOLVED,
+                        segment.identifier.span,
+                        format!(
+                            "the prefix {} is not resolved; it is not a module or not exists",
+                            segment.identifier.symbol
+                        ),
+                    );
+                    return;
+                }
+            };
+        }
+
+        let last = match last {
+            PunctuatedItem::Punctuated { item, .. } => item,
+            PunctuatedItem::NotPunctuated { item } => item,
+        };
+
+        match target.get_non_module_symbol(global_symbol_registry, last.identifier.symbol) {
+            Some(symbol) => {
+                let local_symbol = LocalSymbol::new(
+                    module.clone(),
+                    scope_table.function,
+                    LocalSymbolKind::Global(symbol.clone()),
+                    last.identifier,
+                );
+
+                self.symbols
+                    .entry(scope_table.function)
+                    .or_default()
+                    .insert(id, local_symbol);
+            }
+            None => {
+                module.diagnostics.error(
+                    error_codes::SYMBOL_NOT_FOUND,
+                    last.identifier.span,
+                    match &target {
+                        Namespace::Module(target_module) => format!(
+                            "the symbol {} is not found in the module `{}`",
+                            last.identifier.symbol,
+                            visualize_module_path(&target_module.path)
+                        ),
+                        Namespace::Enum(ast) => format!(
+                            "the variant {} is not found in the enum {}",
+                            last.identifier.symbol, ast.identifier.symbol
+                        ),
+                    },
+                );
+            }
+        }
+    }
+}
+
+fn register_binding(
+    module: &Arc<Module>,
+    id: NodeId,
+    identifier: Id,
+    scope_table: &mut ScopeTable,
+    bindings: &mut PatternBindings,
+) {
+    if let Some(previous) = bindings.bindings.get(&identifier.symbol) {
+        module.diagnostics.error_sub(
+            error_codes::DUPLICATED_BINDING,
+            identifier.span,
+            format!(
+                "the identifier {} is bound multiple times in the same pattern",
+                identifier.symbol
+            ),
+            vec![{
+                module
+                    .diagnostics
+                    .sub_hint(previous.span, "previous binding here".to_owned())
+            }],
+        );
+        return;
+    }
+
+    bindings.bindings.insert(identifier.symbol, identifier);
+    scope_table.new_variable(id, identifier.symbol, bindings.scope_index);
 }
@@ -14,7 +14,7 @@ fuzz_target!(|data: &[u8]| {
         let file = source_map.add_source_file(&s, "test.exc", None::<PathBuf>);
         let (sender, receiver) = mpsc::channel();
         let diagnostics = DiagnosticsSender::new(file.clone(), sender);
-        let token_stream = token_iter(&file);
+        let token_stream = token_iter(&file, &diagnostics);
         let mut id_allocator = NodeIdAllocator::new();
 
         parse_module(token_stream, &mut id_allocator, &diagnostics);
@@ -1,5 +1,8 @@
 use exc_diagnostic::Diagnostics;
-use exc_resolve::{resolve_global, resolve_local, SourceFileResolver};
+use exc_resolve::{
+    resolve_global, resolve_local, GlobalSymbolRegistry, LocalSymbolRegistry, ModuleRegistry,
+    SourceFileResolver,
+};
 use std::path::Path;
 
 #[cfg(test)]
@@ -10,6 +13,17 @@ pub async fn test_module(
     sub_path: impl AsRef<Path>,
     main_file_name: impl AsRef<Path>,
 ) -> Vec<Diagnostics> {
+    let (_, diagnostics) = test_module_with(file, sub_path, main_file_name, |_, _, _| ()).await;
+    diagnostics
+}
+
+/// Same as `test_module`, but also inspects the registries with `f` before they are dropped.
+pub async fn test_module_with<T>(
+    file: impl AsRef<Path>,
+    sub_path: impl AsRef<Path>,
+    main_file_name: impl AsRef<Path>,
+    f: impl FnOnce(&ModuleRegistry, &GlobalSymbolRegistry, &LocalSymbolRegistry) -> T,
+) -> (T, Vec<Diagnostics>) {
     let root_path = file
         .as_ref()
         .parent()
@@ -19,7 +33,7 @@ pub async fn test_module(
         .join(sub_path);
     let mut source_file_resolver = SourceFileResolver::new(root_path, true);
 
-    {
+    let result = {
         let root_module = source_file_resolver
             .resolve_file(main_file_name.as_ref().with_extension("exc"))
             .await
@@ -28,8 +42,14 @@ pub async fn test_module(
         let (module_registry, global_symbol_registry) =
             resolve_global(&mut source_file_resolver, root_module).await;
 
-        let _local_symbol_registry = resolve_local(&module_registry, &global_symbol_registry);
-    }
+        let local_symbol_registry = resolve_local(&module_registry, &global_symbol_registry);
+
+        f(
+            &module_registry,
+            &global_symbol_registry,
+            &local_symbol_registry,
+        )
+    };
 
-    source_file_resolver.into_diagnostics().await
+    (result, source_file_resolver.into_diagnostics().await)
 }
@@ -1 +1,16 @@
+mod argument_count_mismatch;
+mod confusable_identifiers;
+mod const_static_unresolved;
+mod duplicated_binding;
+mod enum_variant_unresolved;
+mod extern_abi_invalid;
+mod for_loop_variable_scope;
+mod interpolated_string_unresolved;
+mod invalid_assignment_target;
+mod invalid_positional_member;
+mod loop_label_invalid;
+mod match_arm_scope;
+mod misplaced_link_name;
 mod simple_unexpected_token;
+mod unknown_attribute;
+mod visibility_scoped_invalid;
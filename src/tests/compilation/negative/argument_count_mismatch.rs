@@ -0,0 +1,13 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn argument_count_mismatch() {
+    let diagnostics = test_module(file!(), "sources", "argument_count_mismatch").await;
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_eq!(diagnostics[0].code, error_codes::VARIADIC_PARAM_NOT_ALLOWED);
+    assert_eq!(diagnostics[1].code, error_codes::ARGUMENT_COUNT_MISMATCH);
+    assert_eq!(diagnostics[2].code, error_codes::ARGUMENT_COUNT_MISMATCH);
+    assert_eq!(diagnostics[3].code, error_codes::ARGUMENT_COUNT_MISMATCH);
+}
@@ -0,0 +1,16 @@
+use crate::tests::test_module;
+use exc_diagnostic::warning_codes;
+
+#[tokio::test]
+async fn confusable_identifiers() {
+    let diagnostics = test_module(file!(), "sources", "confusable_identifiers").await;
+
+    assert_eq!(diagnostics.len(), 2);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, warning_codes::CONFUSABLE_IDENTIFIERS);
+        assert!(diagnostic.message.starts_with("the identifier `"));
+        assert!(!diagnostic.message.contains("``"));
+        assert!(!diagnostic.sub_diagnostics[0].message.contains("``"));
+    }
+}
@@ -0,0 +1,12 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn const_static_unresolved() {
+    let diagnostics = test_module(file!(), "sources", "const_static_unresolved").await;
+
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(diagnostics[0].code, error_codes::DUPLICATED_SYMBOL);
+    assert_eq!(diagnostics[1].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(diagnostics[2].code, error_codes::SYMBOL_NOT_FOUND);
+}
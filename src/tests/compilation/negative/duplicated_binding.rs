@@ -0,0 +1,15 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn duplicated_binding() {
+    let diagnostics = test_module(file!(), "sources", "duplicated_binding").await;
+
+    assert_eq!(diagnostics.len(), 6);
+    assert_eq!(diagnostics[0].code, error_codes::DUPLICATED_BINDING);
+    assert_eq!(diagnostics[1].code, error_codes::DUPLICATED_BINDING);
+    assert_eq!(diagnostics[2].code, error_codes::DUPLICATED_BINDING);
+    assert_eq!(diagnostics[3].code, error_codes::REFUTABLE_PATTERN);
+    assert_eq!(diagnostics[4].code, error_codes::REFUTABLE_PATTERN);
+    assert_eq!(diagnostics[5].code, error_codes::DUPLICATED_BINDING);
+}
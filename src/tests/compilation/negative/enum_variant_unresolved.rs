@@ -0,0 +1,16 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn enum_variant_unresolved() {
+    let diagnostics = test_module(file!(), "sources", "enum_variant_unresolved").await;
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_eq!(diagnostics[0].code, error_codes::DUPLICATED_SYMBOL);
+    assert_eq!(diagnostics[1].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(diagnostics[2].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(
+        diagnostics[3].code,
+        error_codes::PATH_PREFIX_IS_NOT_RESOLVED
+    );
+}
@@ -0,0 +1,13 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn extern_abi_invalid() {
+    let diagnostics = test_module(file!(), "sources", "extern_abi_invalid").await;
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_eq!(diagnostics[0].code, error_codes::UNKNOWN_ABI);
+    assert_eq!(diagnostics[1].code, error_codes::INVALID_LINK_NAME);
+    assert_eq!(diagnostics[2].code, error_codes::INVALID_LINK_NAME);
+    assert_eq!(diagnostics[3].code, error_codes::INVALID_LINK_NAME);
+}
@@ -0,0 +1,10 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn interpolated_string_unresolved() {
+    let diagnostics = test_module(file!(), "sources", "interpolated_string_unresolved").await;
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, error_codes::SYMBOL_NOT_FOUND);
+}
@@ -0,0 +1,12 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn invalid_assignment_target() {
+    let mut diagnostics = test_module(file!(), "sources", "invalid_assignment_target").await;
+    diagnostics.sort_by_key(|diagnostics| diagnostics.code);
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].code, error_codes::INVALID_PLACE_EXPRESSION);
+    assert_eq!(diagnostics[1].code, error_codes::SYMBOL_NOT_FOUND);
+}
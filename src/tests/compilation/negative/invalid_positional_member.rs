@@ -0,0 +1,17 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn invalid_positional_member() {
+    let diagnostics = test_module(file!(), "sources", "invalid_positional_member").await;
+
+    assert_eq!(diagnostics.len(), 5);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, error_codes::INVALID_POSITIONAL_MEMBER);
+    }
+
+    assert_eq!(diagnostics[0].message, "invalid positional member `0x1`");
+    assert_eq!(diagnostics[3].message, "invalid positional member `01`");
+    assert_eq!(diagnostics[4].message, "invalid positional member `1_0`");
+}
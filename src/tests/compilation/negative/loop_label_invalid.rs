@@ -0,0 +1,15 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn loop_label_invalid() {
+    let diagnostics = test_module(file!(), "sources", "loop_label_invalid").await;
+
+    assert_eq!(diagnostics.len(), 5);
+    assert_eq!(diagnostics[0].code, error_codes::BREAK_OUTSIDE_OF_LOOP);
+    assert_eq!(diagnostics[1].code, error_codes::BREAK_OUTSIDE_OF_LOOP);
+    assert_eq!(diagnostics[2].code, error_codes::UNDECLARED_LABEL);
+    // a closure can't leave the loop it's defined in
+    assert_eq!(diagnostics[3].code, error_codes::BREAK_OUTSIDE_OF_LOOP);
+    assert_eq!(diagnostics[4].code, error_codes::UNDECLARED_LABEL);
+}
@@ -0,0 +1,13 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn match_arm_scope() {
+    let diagnostics = test_module(file!(), "sources", "match_arm_scope").await;
+
+    assert_eq!(diagnostics.len(), 4);
+    assert_eq!(diagnostics[0].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(diagnostics[1].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(diagnostics[2].code, error_codes::SYMBOL_NOT_FOUND);
+    assert_eq!(diagnostics[3].code, error_codes::SYMBOL_NOT_FOUND);
+}
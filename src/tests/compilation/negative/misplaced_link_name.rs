@@ -0,0 +1,13 @@
+use crate::tests::test_module;
+use exc_diagnostic::warning_codes;
+
+#[tokio::test]
+async fn misplaced_link_name() {
+    let diagnostics = test_module(file!(), "sources", "misplaced_link_name").await;
+
+    assert_eq!(diagnostics.len(), 4);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, warning_codes::MISPLACED_ATTRIBUTE);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::tests::test_module;
+use exc_diagnostic::warning_codes;
+
+#[tokio::test]
+async fn unknown_attribute() {
+    let diagnostics = test_module(file!(), "sources", "unknown_attribute").await;
+
+    assert_eq!(diagnostics.len(), 2);
+
+    for diagnostic in &diagnostics {
+        assert_eq!(diagnostic.code, warning_codes::UNKNOWN_ATTRIBUTE);
+    }
+}
@@ -0,0 +1,24 @@
+use crate::tests::test_module;
+use exc_diagnostic::error_codes;
+
+#[tokio::test]
+async fn visibility_scoped_invalid() {
+    let diagnostics = test_module(file!(), "sources", "visibility_scoped_invalid").await;
+
+    // the errors come from several modules, so they're compared regardless of the order
+    let mut codes = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.code)
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    assert_eq!(
+        codes,
+        vec![
+            error_codes::MODULE_HAS_NO_SUPER,
+            error_codes::SYMBOL_IS_NOT_VISIBLE,
+            error_codes::SYMBOL_IS_NOT_VISIBLE,
+            error_codes::INVALID_VISIBILITY_PATH,
+        ]
+    );
+}
@@ -1 +1,19 @@
+mod array_index;
+mod block_like_expr;
+mod closure_captures;
+mod condition_struct_literal;
+mod const_static;
+mod enum_def;
+mod extern_abi;
+mod for_loop;
+mod glob_import;
+mod interpolated_string;
+mod let_pattern;
+mod loop_label;
+mod match_expr;
+mod nested_block_scope;
+mod range;
 mod simple_hello_world;
+mod tuple;
+mod variadic_prototype;
+mod visibility_scoped;
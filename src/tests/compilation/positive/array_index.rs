@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn array_index() {
+    let diagnostics = test_module(file!(), "sources", "array_index").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn block_like_expr() {
+    let diagnostics = test_module(file!(), "sources", "block_like_expr").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,39 @@
+use crate::tests::test_module_with;
+
+#[tokio::test]
+async fn closure_captures() {
+    let (mut captures, diagnostics) =
+        test_module_with(file!(), "sources", "closure_captures", |_, _, registry| {
+            registry
+                .closures()
+                .map(|closure| {
+                    let names = closure
+                        .captures
+                        .iter()
+                        .map(|capture| capture.identifier.symbol.to_str())
+                        .collect::<Vec<_>>();
+                    (closure.id, names)
+                })
+                .collect::<Vec<_>>()
+        })
+        .await;
+
+    assert_eq!(diagnostics.len(), 0);
+
+    // the closures in the order of appearance
+    captures.sort_by_key(|(id, _)| *id);
+    let captures = captures
+        .into_iter()
+        .map(|(_, names)| names)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        captures,
+        vec![
+            vec!["base"],
+            vec!["scale", "base"],
+            vec!["offset", "base"],
+            vec![],
+        ]
+    );
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn condition_struct_literal() {
+    let diagnostics = test_module(file!(), "sources", "condition_struct_literal").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn const_static() {
+    let diagnostics = test_module(file!(), "sources", "const_static").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn enum_def() {
+    let diagnostics = test_module(file!(), "sources", "enum_def").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,35 @@
+use crate::tests::test_module_with;
+use exc_resolve::{Abi, GlobalSymbolKind};
+
+#[tokio::test]
+async fn extern_abi() {
+    let (mut prototypes, diagnostics) =
+        test_module_with(file!(), "sources", "extern_abi", |modules, symbols, _| {
+            modules
+                .modules()
+                .flat_map(|module| symbols.non_module_symbols(module).into_iter().flatten())
+                .filter_map(|symbol| match &symbol.kind {
+                    GlobalSymbolKind::Prototype(prototype) => Some((
+                        prototype.prototype_def.identifier.symbol.to_str(),
+                        prototype.abi,
+                        prototype.link_name.to_str(),
+                    )),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .await;
+
+    assert_eq!(diagnostics.len(), 0);
+
+    prototypes.sort_by_key(|(name, _, _)| *name);
+    assert_eq!(
+        prototypes,
+        vec![
+            ("abs", Abi::C, "abs"),
+            ("exit", Abi::C, "exit"),
+            ("print", Abi::C, "puts"),
+            ("sleep", Abi::System, "sleep"),
+        ]
+    );
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn for_loop() {
+    let diagnostics = test_module(file!(), "sources", "for_loop").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn glob_import() {
+    let diagnostics = test_module(file!(), "sources", "glob_import").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
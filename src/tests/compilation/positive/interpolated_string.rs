@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn interpolated_string() {
+    let diagnostics = test_module(file!(), "sources", "interpolated_string").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn let_pattern() {
+    let diagnostics = test_module(file!(), "sources", "let_pattern").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn loop_label() {
+    let diagnostics = test_module(file!(), "sources", "loop_label").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
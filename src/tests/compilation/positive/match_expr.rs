@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn match_expr() {
+    let diagnostics = test_module(file!(), "sources", "match_expr").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
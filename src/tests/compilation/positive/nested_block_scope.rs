@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn nested_block_scope() {
+    let diagnostics = test_module(file!(), "sources", "nested_block_scope").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
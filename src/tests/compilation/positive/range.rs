@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn range() {
+    let diagnostics = test_module(file!(), "sources", "range").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn tuple() {
+    let diagnostics = test_module(file!(), "sources", "tuple").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn variadic_prototype() {
+    let diagnostics = test_module(file!(), "sources", "variadic_prototype").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}
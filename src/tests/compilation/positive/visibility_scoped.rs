@@ -0,0 +1,8 @@
+use crate::tests::test_module;
+
+#[tokio::test]
+async fn visibility_scoped() {
+    let diagnostics = test_module(file!(), "sources", "visibility_scoped").await;
+
+    assert_eq!(diagnostics.len(), 0);
+}